@@ -3,7 +3,11 @@ use super::*;
 impl<'a> Display for AstGroup<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let children: Vec<_> = self.children.iter().map(|s| s.to_string()).collect();
-        write!(f, "{}({})", self.head, children.join(" "))
+        write!(f, "{}({})", self.head, children.join(" "))?;
+        if self.important {
+            write!(f, "!")?
+        }
+        Ok(())
     }
 }
 
@@ -29,6 +33,12 @@ impl<'a> Display for AstStyle<'a> {
             None => {}
             Some(s) => write!(f, "-[{}]", s)?,
         }
+        if let Some(s) = self.modifier {
+            write!(f, "/{}", s)?
+        }
+        if self.important {
+            write!(f, "!")?
+        }
         Ok(())
     }
 }
@@ -39,6 +49,14 @@ impl<'a> Display for ASTVariant<'a> {
             write!(f, "not-")?
         }
         write!(f, "{}", self.names.join("-"))?;
+        match self.arbitrary {
+            Some(s) if self.names.is_empty() => write!(f, "[{}]", s)?,
+            Some(s) => write!(f, "-[{}]", s)?,
+            None => {},
+        }
+        if let Some(s) = self.modifier {
+            write!(f, "/{}", s)?
+        }
         match self.pseudo {
             true => write!(f, "::"),
             false => write!(f, ":"),
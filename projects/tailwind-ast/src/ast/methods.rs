@@ -13,9 +13,13 @@ impl<'a> AstGroup<'a> {
     #[inline]
     pub fn expand(self, styles: &mut Vec<AstStyle<'a>>) {
         let head = &self.head;
+        let start = styles.len();
         for item in self.children {
             item.expand_with_head(styles, head)
         }
+        if self.important {
+            styles[start..].iter_mut().for_each(|s| s.important = true)
+        }
     }
 }
 
@@ -25,7 +29,7 @@ impl<'a> Add<AstGroup<'a>> for AstStyle<'a> {
     fn add(self, rhs: AstGroup<'a>) -> Self::Output {
         let mut head = self;
         head.add_assign(&rhs.head);
-        AstGroup { important: false, head, children: rhs.children }
+        AstGroup { important: rhs.important, head, children: rhs.children }
     }
 }
 
@@ -59,9 +63,11 @@ impl<'a> AstGroupItem<'a> {
 impl<'a> AddAssign<&AstStyle<'a>> for AstStyle<'a> {
     #[inline]
     fn add_assign(&mut self, rhs: &AstStyle<'a>) {
+        self.important = self.important || rhs.important;
         self.negative = merge_negative(self.negative, rhs.negative);
         self.variants.extend(rhs.variants.iter().cloned());
-        self.arbitrary = self.arbitrary.or(self.arbitrary);
+        self.arbitrary = rhs.arbitrary.or(self.arbitrary);
+        self.modifier = rhs.modifier.or(self.modifier);
         match rhs.is_self_reference() {
             true => {}
             false => self.elements.extend(rhs.elements.iter().cloned()),
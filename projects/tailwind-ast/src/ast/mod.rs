@@ -4,19 +4,20 @@ mod methods;
 mod parse;
 #[cfg(test)]
 mod tests;
+pub(crate) use self::parse::split_classes;
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_till1},
+    bytes::complete::{tag, take_till1, take_while1},
     character::complete::{alphanumeric1, char, multispace1},
-    combinator::opt,
+    combinator::{map, opt, recognize},
     error::Error,
     multi::{many0, separated_list0},
-    sequence::{delimited, tuple},
+    sequence::{delimited, preceded, tuple},
     Err, IResult,
 };
 use std::{
     fmt::{Display, Formatter},
-    ops::{Add, AddAssign},
+    ops::{Add, AddAssign, Range},
 };
 
 /// Decompose a string into tailwind instructions
@@ -34,6 +35,35 @@ pub fn parse_tailwind(input: &str) -> Result<Vec<AstStyle>, Err<Error<&str>>> {
     Ok(out)
 }
 
+/// Decompose a string into tailwind instructions, skipping the classes that can not be parsed
+///
+/// Unlike [`parse_tailwind`], one bad class does not fail the others, e.g. `p-4 bg-[#fff m-2` keeps `p-4` and `m-2`.
+pub fn parse_tailwind_tolerant(input: &str) -> (Vec<AstStyle<'_>>, Vec<AstError>) {
+    let mut styles = vec![];
+    let mut errors = vec![];
+    for (range, unclosed) in split_classes(input) {
+        if let Some(closing) = unclosed {
+            errors.push(AstError { range, message: format!("missing `{}`", closing) });
+            continue;
+        }
+        match AstGroupItem::parse(&input[range.clone()]) {
+            Ok(("", item)) => item.expand(&mut styles),
+            Ok((rest, _)) => errors.push(AstError { range, message: format!("unexpected `{}`", rest) }),
+            Err(_) => errors.push(AstError { range, message: "invalid class".to_string() }),
+        }
+    }
+    (styles, errors)
+}
+
+/// A class rejected by [`parse_tailwind_tolerant`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AstError {
+    /// Byte range of the class in the input
+    pub range: Range<usize>,
+    /// What is wrong with the class, e.g. ``missing `]` ``
+    pub message: String,
+}
+
 /// `variant:ast-style(grouped)`
 #[derive(Clone, Debug, PartialEq, Default)]
 pub struct AstGroup<'a> {
@@ -67,6 +97,8 @@ pub struct AstStyle<'a> {
     pub elements: Vec<&'a str>,
     /// Is a arbitrary value
     pub arbitrary: Option<&'a str>,
+    /// The modifier after the slash, `50` in `bg-red-500/50`, `[.37]` in `bg-red-500/[.37]` or `7` in `text-lg/7`
+    pub modifier: Option<&'a str>,
 }
 
 /// `-[.+]`
@@ -91,8 +123,8 @@ pub struct AstReference {}
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AstImportant {}
 
-/// `(not-)?variant:pseudo::`
-#[derive(Clone, Debug, PartialEq)]
+/// `(not-)?variant(-[arbitrary])?(/modifier)?:pseudo::`
+#[derive(Clone, Debug, PartialEq, Default)]
 pub struct ASTVariant<'a> {
     /// `not-`
     pub not: bool,
@@ -100,4 +132,8 @@ pub struct ASTVariant<'a> {
     pub pseudo: bool,
     /// `name-space`
     pub names: Vec<&'a str>,
+    /// `-[900px]`
+    pub arbitrary: Option<&'a str>,
+    /// `/sidebar`
+    pub modifier: Option<&'a str>,
 }
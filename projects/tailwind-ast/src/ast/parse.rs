@@ -43,14 +43,20 @@ impl<'a> AstGroupItem<'a> {
 }
 
 impl<'a> AstStyle<'a> {
-    /// `v:v::-?a-a-a-[A]`
+    /// `v:v::-?a-a-a-[A](/M)?`
     #[inline]
     pub fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        let (rest, (variants, negative, elements, arbitrary, important)) = tuple((
+        // `50` in `red-500/50`, `[.37]` keeps its brackets so the style prints back as written
+        let modifier = alt((
+            recognize(delimited_paired('[', ']')),
+            take_while1(|c: char| c.is_alphanumeric() || c == '.' || c == '%'),
+        ));
+        let (rest, (variants, negative, elements, arbitrary, modifier, important)) = tuple((
             many0(ASTVariant::parse),
             opt(char('-')),
             opt(AstElements::parse),
             opt(AstArbitrary::parse),
+            opt(preceded(char('/'), modifier)),
             opt(char('!')),
         ))(input)?;
 
@@ -62,6 +68,7 @@ impl<'a> AstStyle<'a> {
                 variants,
                 elements: elements.unwrap_or_default().elements,
                 arbitrary: arbitrary.map(|s| s.arbitrary),
+                modifier,
             },
         ))
     }
@@ -80,7 +87,7 @@ impl<'a> AstElements<'a> {
     fn parse_head(input: &'a str) -> IResult<&'a str, &'a str> {
         let stop = |c: char| -> bool {
             // space
-            matches!(c, ' ' | '\n' | '\r' | '-' | '[' | ']' | '(' | ')')
+            matches!(c, ' ' | '\n' | '\r' | '-' | '[' | ']' | '(' | ')' | '!' | '/')
         };
         take_till1(stop)(input)
     }
@@ -107,17 +114,28 @@ impl<'a> ASTVariant<'a> {
         }
         Ok((rest, v))
     }
-    /// `(not-)?(ALPHA)(-ALPHA)*`
+    /// `(not-)?(ALPHA)(-ALPHA)*(-[ANY+])?(/NAME)?` or `(not-)?[ANY+]`
     ///
     /// eg:
     /// - `not-focus`
     /// - `not-last-child`
+    /// - `min-[900px]`
+    /// - `group-hover/sidebar`
+    /// - `data-[state=open]`
+    /// - `[&:nth-child(3)]`
     #[inline]
     fn parse_one(input: &'a str) -> IResult<&'a str, Self> {
         let not = opt(tuple((tag("not"), tag("-"))));
         let vs = separated_list0(tag("-"), alphanumeric1);
+        let name = take_while1(|c: char| c.is_alphanumeric() || c == '-' || c == '_');
         let (rest, (not, names)) = tuple((not, vs))(input)?;
-        Ok((rest, Self { not: not.is_some(), pseudo: false, names }))
+        // the selector stands alone in `[&>*]`, and follows a `-` in `has-[>img]`
+        let (rest, arbitrary) = match names.is_empty() {
+            true => map(delimited_paired('[', ']'), Some)(rest)?,
+            false => opt(preceded(char('-'), delimited_paired('[', ']')))(rest)?,
+        };
+        let (rest, modifier) = opt(preceded(char('/'), name))(rest)?;
+        Ok((rest, Self { not: not.is_some(), pseudo: false, names, arbitrary, modifier }))
     }
     /// https://developer.mozilla.org/en-US/docs/Web/CSS/Pseudo-elements#index
     #[rustfmt::skip] #[inline]
@@ -210,3 +228,78 @@ fn take_until_unbalanced(
         }
     }
 }
+
+/// Split the classes at whitespace outside of `()` and `[]`
+///
+/// A class with an unclosed bracket ends at the next whitespace instead, and carries the missing closing bracket.
+/// An unbalanced group such as `hover:(p-4 bg-[#fff m-2)` is kept whole, so none of its classes lose the variants.
+pub(crate) fn split_classes(input: &str) -> Vec<(Range<usize>, Option<char>)> {
+    let mut out = vec![];
+    let mut start = 0;
+    loop {
+        start += input[start..].len() - input[start..].trim_start().len();
+        if start >= input.len() {
+            return out;
+        }
+        let mut stack = vec![];
+        let mut escaped = false;
+        let mut end = input.len();
+        // the first whitespace inside a bracket, and the bracket it leaves open
+        let mut fallback = None;
+        for (i, c) in input[start..].char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '(' => stack.push(')'),
+                '[' => stack.push(']'),
+                ')' | ']' if stack.last() == Some(&c) => {
+                    stack.pop();
+                },
+                c if c.is_whitespace() && stack.is_empty() => {
+                    end = start + i;
+                    break;
+                },
+                c if c.is_whitespace() && fallback.is_none() => fallback = Some((start + i, stack.last().copied())),
+                _ => {},
+            }
+        }
+        let mut unclosed = None;
+        if stack.first() == Some(&')') {
+            (end, unclosed) = group_end(input, start);
+        }
+        else if let Some(closing) = stack.last() {
+            (end, unclosed) = fallback.unwrap_or((input.len(), Some(*closing)));
+        }
+        out.push((start..end, unclosed));
+        start = end;
+    }
+}
+
+/// The end of an unbalanced group starting at `start`, a `)` closes the brackets left open inside the group
+fn group_end(input: &str, start: usize) -> (usize, Option<char>) {
+    let mut stack = vec![];
+    let mut escaped = false;
+    let mut unclosed = None;
+    for (i, c) in input[start..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '(' => stack.push(')'),
+            '[' => stack.push(']'),
+            ']' if stack.last() == Some(&']') => {
+                stack.pop();
+            },
+            ')' if stack.contains(&')') => {
+                while let Some(closing) = stack.pop() {
+                    if closing == ')' {
+                        break;
+                    }
+                    unclosed = unclosed.or(Some(closing));
+                }
+            },
+            c if c.is_whitespace() && stack.is_empty() => return (start + i, unclosed),
+            _ => {},
+        }
+    }
+    (input.len(), unclosed.or(stack.last().copied()))
+}
@@ -32,11 +32,35 @@ fn test_arbitrary_bad1() {
 #[test]
 fn test_variant() {
     let input = ASTVariant::parse("not-hover::").unwrap().1;
-    let output = ASTVariant { not: true, pseudo: true, names: vec!["hover"] };
+    let output = ASTVariant { not: true, pseudo: true, names: vec!["hover"], ..Default::default() };
     assert_eq!(input, output);
     let input = ASTVariant::parse("sm:").unwrap().1;
-    let output = ASTVariant { not: false, pseudo: false, names: vec!["sm"] };
+    let output = ASTVariant { not: false, pseudo: false, names: vec!["sm"], ..Default::default() };
     assert_eq!(input, output);
+    let input = ASTVariant::parse("group-hover/sidebar:").unwrap().1;
+    let output = ASTVariant { names: vec!["group", "hover"], modifier: Some("sidebar"), ..Default::default() };
+    assert_eq!(input, output);
+    let input = ASTVariant::parse("min-[900px]:").unwrap().1;
+    let output = ASTVariant { names: vec!["min"], arbitrary: Some("900px"), ..Default::default() };
+    assert_eq!(input, output);
+    let input = ASTVariant::parse("[&:nth-child(3)]:").unwrap().1;
+    let output = ASTVariant { arbitrary: Some("&:nth-child(3)"), ..Default::default() };
+    assert_eq!(input, output);
+    let input = ASTVariant::parse("[&_[data-open]]:").unwrap().1;
+    let output = ASTVariant { arbitrary: Some("&_[data-open]"), ..Default::default() };
+    assert_eq!(input, output);
+    let input = ASTVariant::parse("data-[state=open]:").unwrap().1;
+    let output = ASTVariant { names: vec!["data"], arbitrary: Some("state=open"), ..Default::default() };
+    assert_eq!(input, output);
+    let input = ASTVariant::parse("supports-[display:grid]:").unwrap().1;
+    let output = ASTVariant { names: vec!["supports"], arbitrary: Some("display:grid"), ..Default::default() };
+    assert_eq!(input, output);
+    let input = ASTVariant::parse("not-has-[:checked]:").unwrap().1;
+    let output = ASTVariant { not: true, names: vec!["has"], arbitrary: Some(":checked"), ..Default::default() };
+    assert_eq!(input, output);
+    let input = AstStyle::parse("[&>*]:underline").unwrap().1;
+    assert_eq!(input.to_string(), "[&>*]:underline");
+    assert!(ASTVariant::parse("data[state=open]:").is_err());
 }
 
 #[test]
@@ -63,14 +87,28 @@ fn test_style() {
     let output = AstStyle {
         negative: false,
         variants: vec![
-            ASTVariant { not: true, pseudo: false, names: vec!["hover"] },
-            ASTVariant { not: false, pseudo: false, names: vec!["sm"] },
+            ASTVariant { not: true, pseudo: false, names: vec!["hover"], ..Default::default() },
+            ASTVariant { not: false, pseudo: false, names: vec!["sm"], ..Default::default() },
         ],
         elements: vec!["text", "red"],
         arbitrary: Some("200/50"),
         ..Default::default()
     };
     assert_eq!(input, output);
+    let input = AstStyle::parse("text-[var(--brand)]/50").unwrap().1;
+    let output = AstStyle { elements: vec!["text"], arbitrary: Some("var(--brand)"), modifier: Some("50"), ..Default::default() };
+    assert_eq!(input, output);
+    assert_eq!(input.to_string(), "text-[var(--brand)]/50");
+    let input = AstStyle::parse("bg-red-500/[.37]").unwrap().1;
+    let output = AstStyle { elements: vec!["bg", "red", "500"], modifier: Some("[.37]"), ..Default::default() };
+    assert_eq!(input, output);
+    assert_eq!(input.to_string(), "bg-red-500/[.37]");
+    let input = AstStyle::parse("text-lg/7").unwrap().1;
+    let output = AstStyle { elements: vec!["text", "lg"], modifier: Some("7"), ..Default::default() };
+    assert_eq!(input, output);
+    let input = AstStyle::parse("w-1/2").unwrap().1;
+    let output = AstStyle { elements: vec!["w", "1"], modifier: Some("2"), ..Default::default() };
+    assert_eq!(input, output);
 }
 
 // #[test]
@@ -100,7 +138,7 @@ fn test_group() {
             }),
             Styled(AstStyle {
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["sm"] }],
+                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["sm"], ..Default::default() }],
                 elements: vec!["auto"],
                 arbitrary: None,
                 ..Default::default()
@@ -128,7 +166,7 @@ fn test_group() {
             }),
             Styled(AstStyle {
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["hover"] }],
+                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["hover"], ..Default::default() }],
                 elements: vec!["6"],
                 arbitrary: None,
                 ..Default::default()
@@ -136,7 +174,7 @@ fn test_group() {
             Grouped(AstGroup {
                 head: AstStyle {
                     negative: false,
-                    variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["md"] }],
+                    variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["md"], ..Default::default() }],
                     elements: vec![],
                     arbitrary: None,
                     ..Default::default()
@@ -155,6 +193,7 @@ fn test_group() {
                             not: false,
                             pseudo: false,
                             names: vec!["hover"],
+                            ..Default::default()
                         }],
                         elements: vec!["6"],
                         arbitrary: None,
@@ -179,21 +218,21 @@ fn test_group() {
         children: vec![
             Styled(AstStyle {
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["hover"] }],
+                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["hover"], ..Default::default() }],
                 elements: vec!["&"],
                 arbitrary: None,
                 ..Default::default()
             }),
             Styled(AstStyle {
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["focus"] }],
+                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["focus"], ..Default::default() }],
                 elements: vec!["&"],
                 arbitrary: None,
                 ..Default::default()
             }),
             Styled(AstStyle {
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["active"] }],
+                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["active"], ..Default::default() }],
                 elements: vec!["&"],
                 arbitrary: None,
                 ..Default::default()
@@ -217,6 +256,7 @@ fn test_expand() {
     check_expand("not-hover:sm:text-red-200", "not-hover:sm:text-red-200");
     check_expand("w(full sm:auto)", "w-full sm:w-auto");
     check_expand("w(1/2 sm:1/3 lg:1/6) p-2", "w-1/2 sm:w-1/3 lg:w-1/6 p-2");
+    check_expand("bg(red-500/50 hover:blue-500/[.2])", "bg-red-500/50 hover:bg-blue-500/[.2]");
     check_expand(
         "rotate(-3 hover:6 md:(3 hover:-6))",
         "-rotate-3 hover:rotate-6 md:rotate-3 md:hover:-rotate-6",
@@ -239,4 +279,23 @@ fn test_expand() {
         "#,
         "bg-red-500 shadow-xs sm:bg-red-600 sm:shadow-sm md:bg-red-700 md:shadow-md lg:bg-red-800 lg:shadow-xl",
     );
+    check_expand("p-4! m(2 x-auto)!", "p-4! m-2! m-x-auto!");
+    check_expand("hover:(p-4 md:(m-2)!)", "hover:p-4 hover:md:m-2!");
 }
+
+#[test]
+fn test_tolerant() {
+    let input = "p-4 hover:(bg-red-500 m-2 bg-[#fff text-lg) w-1/2";
+    let (styles, errors) = parse_tailwind_tolerant(input);
+    let styles: Vec<_> = styles.iter().map(|s| s.to_string()).collect();
+    // the classes of the broken group are not reported without its variant
+    assert_eq!(styles, ["p-4", "w-1/2"]);
+    assert_eq!(errors, [AstError { range: 4..43, message: "missing `]`".to_string() }]);
+    let (styles, errors) = parse_tailwind_tolerant("m-1 hover:(p-4 m-2");
+    assert_eq!(styles.len(), 1);
+    assert_eq!(errors, [AstError { range: 4..18, message: "missing `)`".to_string() }]);
+    let (styles, errors) = parse_tailwind_tolerant("  sm:(p-4 m-2)  a) ");
+    assert_eq!(styles.len(), 2);
+    assert_eq!(errors, [AstError { range: 16..18, message: "unexpected `)`".to_string() }]);
+}
+
@@ -1,6 +1,198 @@
+use crate::{ast::split_classes, parse_tailwind, parse_tailwind_tolerant, ASTVariant, AstError, AstStyle};
+use nom::{error::Error, Err};
+use std::{collections::HashMap, ops::Range};
+use xxhash_rust::xxh3::xxh3_64;
 
-
-#[derive(Copy, Clone, Debug)]
+/// A parser which remembers the styles of every class string it has seen
+///
+/// Large documents repeat the same class attribute many times, a repeated string is rebuilt from the cache
+/// instead of parsed again.
+///
+/// ```
+/// # use tailwind_ast::TailwindParser;
+/// let mut parser = TailwindParser::default();
+/// let first = parser.parse("p-4 hover:(m-2 bg-red-500)").unwrap();
+/// let again = parser.parse("p-4 hover:(m-2 bg-red-500)").unwrap();
+/// assert_eq!(first, again);
+/// assert_eq!(parser.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
 pub struct TailwindParser {
+    cache: HashMap<u64, CachedClass>,
+}
+
+/// The input and its styles, as byte ranges into the input
+#[derive(Clone, Debug)]
+struct CachedClass {
+    input: Box<str>,
+    styles: Vec<CachedStyle>,
+}
+
+#[derive(Clone, Debug)]
+struct CachedStyle {
+    important: bool,
+    negative: bool,
+    variants: Vec<CachedVariant>,
+    elements: Vec<Range<usize>>,
+    arbitrary: Option<Range<usize>>,
+    modifier: Option<Range<usize>>,
+}
+
+#[derive(Clone, Debug)]
+struct CachedVariant {
+    not: bool,
+    pseudo: bool,
+    names: Vec<Range<usize>>,
+    arbitrary: Option<Range<usize>>,
+    modifier: Option<Range<usize>>,
+}
+
+impl TailwindParser {
+    /// Decompose a string into tailwind instructions, same as [`parse_tailwind`]
+    ///
+    /// Errors are not cached.
+    pub fn parse<'a>(&mut self, input: &'a str) -> Result<Vec<AstStyle<'a>>, Err<Error<&'a str>>> {
+        if let Some(styles) = self.get(input) {
+            return Ok(styles);
+        }
+        let styles = parse_tailwind(input)?;
+        // an unclosed bracket is dropped by the strict parser, but must be reported by the tolerant one
+        if split_classes(input).iter().all(|(_, unclosed)| unclosed.is_none()) {
+            self.insert(input, &styles);
+        }
+        Ok(styles)
+    }
+    /// Decompose a string into tailwind instructions, same as [`parse_tailwind_tolerant`]
+    ///
+    /// Only a class string without errors is cached.
+    pub fn parse_tolerant<'a>(&mut self, input: &'a str) -> (Vec<AstStyle<'a>>, Vec<AstError>) {
+        if let Some(styles) = self.get(input) {
+            return (styles, vec![]);
+        }
+        let (styles, errors) = parse_tailwind_tolerant(input);
+        if errors.is_empty() {
+            self.insert(input, &styles);
+        }
+        (styles, errors)
+    }
+    fn get<'a>(&self, input: &'a str) -> Option<Vec<AstStyle<'a>>> {
+        let cached = self.cache.get(&xxh3_64(input.as_bytes()))?;
+        // a hash collision, keep the first one
+        if cached.input.as_ref() != input {
+            return None;
+        }
+        Some(cached.styles.iter().map(|s| s.build(input)).collect())
+    }
+    fn insert(&mut self, input: &str, styles: &[AstStyle]) {
+        if let Some(cached) = styles.iter().map(|s| CachedStyle::new(input, s)).collect() {
+            let class = CachedClass { input: Box::from(input), styles: cached };
+            self.cache.entry(xxh3_64(input.as_bytes())).or_insert(class);
+        }
+    }
+    /// The number of cached class strings
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+    /// Nothing has been cached yet
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+    /// Forget all cached class strings
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cache.clear()
+    }
+}
+
+impl CachedStyle {
+    fn new(input: &str, style: &AstStyle) -> Option<Self> {
+        Some(Self {
+            important: style.important,
+            negative: style.negative,
+            variants: style.variants.iter().map(|v| CachedVariant::new(input, v)).collect::<Option<_>>()?,
+            elements: ranges(input, &style.elements)?,
+            arbitrary: optional_range(input, style.arbitrary)?,
+            modifier: optional_range(input, style.modifier)?,
+        })
+    }
+    fn build<'a>(&self, input: &'a str) -> AstStyle<'a> {
+        AstStyle {
+            important: self.important,
+            negative: self.negative,
+            variants: self.variants.iter().map(|v| v.build(input)).collect(),
+            elements: self.elements.iter().map(|r| &input[r.clone()]).collect(),
+            arbitrary: self.arbitrary.clone().map(|r| &input[r]),
+            modifier: self.modifier.clone().map(|r| &input[r]),
+        }
+    }
+}
+
+impl CachedVariant {
+    fn new(input: &str, variant: &ASTVariant) -> Option<Self> {
+        Some(Self {
+            not: variant.not,
+            pseudo: variant.pseudo,
+            names: ranges(input, &variant.names)?,
+            arbitrary: optional_range(input, variant.arbitrary)?,
+            modifier: optional_range(input, variant.modifier)?,
+        })
+    }
+    fn build<'a>(&self, input: &'a str) -> ASTVariant<'a> {
+        ASTVariant {
+            not: self.not,
+            pseudo: self.pseudo,
+            names: self.names.iter().map(|r| &input[r.clone()]).collect(),
+            arbitrary: self.arbitrary.clone().map(|r| &input[r]),
+            modifier: self.modifier.clone().map(|r| &input[r]),
+        }
+    }
+}
+
+/// Where the slice lies in the input, `None` if it was not sliced from the input
+fn range(input: &str, slice: &str) -> Option<Range<usize>> {
+    let start = (slice.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    let end = start + slice.len();
+    (end <= input.len()).then_some(start..end)
+}
+
+fn ranges(input: &str, slices: &[&str]) -> Option<Vec<Range<usize>>> {
+    slices.iter().map(|s| range(input, s)).collect()
+}
+
+/// `Some(None)` for a missing slice, `None` if it was not sliced from the input
+fn optional_range(input: &str, slice: Option<&str>) -> Option<Option<Range<usize>>> {
+    match slice {
+        Some(s) => range(input, s).map(Some),
+        None => Some(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-}
\ No newline at end of file
+    #[test]
+    fn test_cached() {
+        let mut parser = TailwindParser::default();
+        let class = "sm:hover:(p-4 -m-[3px]) text-[var(--x)]/50 group-hover/item:underline!";
+        let first = parser.parse(class).unwrap();
+        // another allocation of the same class
+        let copy = class.to_string();
+        let again = parser.parse(&copy).unwrap();
+        assert_eq!(again, parse_tailwind(&copy).unwrap());
+        assert_eq!(first, again);
+        assert!(again.iter().all(|s| s.elements.iter().all(|e| copy.as_bytes().as_ptr_range().contains(&e.as_ptr()))));
+        assert_eq!(parser.len(), 1);
+        let (_, errors) = parser.parse_tolerant(class);
+        assert!(errors.is_empty());
+        // the strict parser drops the unclosed bracket, the tolerant one still reports it
+        assert!(parser.parse("p-4 w-[10px").is_ok());
+        let (styles, errors) = parser.parse_tolerant("p-4 w-[10px");
+        assert_eq!((styles.len(), errors.len()), (1, 1));
+        assert_eq!(parser.len(), 1);
+        parser.clear();
+        assert!(parser.is_empty());
+    }
+}
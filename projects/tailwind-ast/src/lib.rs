@@ -12,4 +12,4 @@ mod ast;
 mod codegen;
 mod utils;
 
-pub use self::{ast::*, utils::*};
+pub use self::{ast::*, codegen::TailwindParser, utils::*};
@@ -0,0 +1,142 @@
+use std::{
+    fs::{create_dir_all, write},
+    path::Path,
+};
+
+use tailwind_rs::{Result, TailwindBuilder, TailwindError};
+
+/// A page using a few utilities, so that the first run has something to compile
+const STARTER_HTML: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <title>Hello, tailwind</title>
+</head>
+<body class="bg-gray-50 text-gray-900">
+<main class="mx-auto max-w-2xl p-8">
+    <h1 class="text-3xl font-bold">Hello, tailwind</h1>
+    <p class="mt-4 text-gray-600 md:text-lg">
+        Edit this page, then run <code class="text-sky-600">tailwind --css style.css --in-place</code>.
+    </p>
+    <a class="mt-8 inline-block rounded-lg bg-sky-500 px-4 py-2 text-white hover:bg-sky-600" href="https://tailwindcss.com/docs">
+        Documentation
+    </a>
+</main>
+</body>
+</html>
+"#;
+
+/// Write `tailwind.toml`, and `index.html` if asked, refusing to overwrite anything unless `force`
+pub fn scaffold(dir: &Path, html: bool, force: bool) -> Result<()> {
+    let mut files = vec![(dir.join("tailwind.toml"), config_template())];
+    if html {
+        files.push((dir.join("index.html"), STARTER_HTML.to_string()));
+    }
+    let existing: Vec<_> = files.iter().filter(|(path, _)| path.exists()).map(|(path, _)| path.display().to_string()).collect();
+    if !force && !existing.is_empty() {
+        return Err(TailwindError::runtime_error(format!("{} already exists, pass `--force` to overwrite", existing.join(", "))));
+    }
+    create_dir_all(dir)?;
+    for (path, text) in files {
+        write(&path, text)?;
+        println!("write {}", path.display());
+    }
+    println!("Compile the workspace with `tailwind --css style.css --in-place`");
+    Ok(())
+}
+
+/// A `tailwind.toml` spelling out the builtin defaults, so that they can be edited in place
+fn config_template() -> String {
+    let builder = TailwindBuilder::default();
+    let preflight = &builder.preflight;
+    let flags = [
+        ("disable", preflight.disable, "drop all the base styles"),
+        ("default_vars", preflight.default_vars, "`--font-sans`, `--default-transition-duration` and others on `:root`"),
+        ("global_reset", preflight.global_reset, "reset box-sizing, margins, padding and borders"),
+        ("html_base", preflight.html_base, "line-height, font-family and other root styles"),
+        ("unstyle_headings", preflight.unstyle_headings, "headings inherit their font size and weight"),
+        ("unstyle_links", preflight.unstyle_links, "links inherit their color and text decoration"),
+        ("unstyle_lists", preflight.unstyle_lists, "lists lose their bullets, margin and padding"),
+        ("block_level_media", preflight.block_level_media, "images, videos and other media are `display: block`"),
+        ("reset_tables", preflight.reset_tables, "collapse table borders and reset text-indent"),
+        ("reset_forms", preflight.reset_forms, "buttons, inputs and textareas inherit font and colors"),
+        ("hidden_attribute", preflight.hidden_attribute, "elements with `hidden` are not displayed"),
+        ("specific_extras", preflight.specific_extras, "fixes for `<strong>`, `<code>`, `<abbr>`, `<sub>` and `<sup>`"),
+        ("compatibility_fixes", preflight.compatibility_fixes, "normalize form controls across browsers"),
+    ];
+    let mut out = String::from(
+        r#"# Config of the tailwind cli, picked up from the workspace or passed with `tailwind --config tailwind.toml`.
+minify = false
+obfuscate = false
+
+# Source files, relative to the workspace. `node_modules`, `target`, `dist` and `.git` are never scanned.
+# `--pattern` replaces `include` and `--exclude` adds to `exclude`.
+include = ["**/*.html"]
+exclude = []
+
+# The base styles, the values below are the builtin ones.
+[preflight]
+"#,
+    );
+    for (name, value, comment) in flags {
+        out.push_str(&format!("{:<20}= {:<6}# {}\n", name, value, comment));
+    }
+    out.push_str(
+        r#"# css prepended to the base styles
+# custom = "body { min-height: 100vh; }"
+
+# The `theme` follows the shape of `tailwind.config.js`:
+# sections in `[theme]` replace the builtin ones, sections in `[theme.extend]` are merged into them.
+
+# The builtin breakpoints, `md:` applies from 768px.
+[theme.screens]
+"#,
+    );
+    for (name, width) in builder.screens.iter() {
+        out.push_str(&format!("{} = \"{}px\"\n", name, width));
+    }
+    out.push_str(
+        r##"
+# Extra colors, a single one or a palette of weights.
+[theme.extend.colors]
+# primary = "#0EA5E9"
+# brand = { 50 = "#F5F3FF", 500 = "#8B5CF6", 900 = "#4C1D95", DEFAULT = "#8B5CF6" }
+"##,
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{read_to_string, remove_dir_all};
+    use tailwind_rs::CLIConfig;
+
+    #[test]
+    fn test_template() {
+        let config = CLIConfig::load_toml(&config_template()).unwrap();
+        assert_eq!(config.include, ["**/*.html"]);
+        assert_eq!(config.preflight.reset_forms, Some(true));
+        let mut builder = config.builder();
+        assert_eq!(builder.screens.try_get_width("2xl"), Ok(1536));
+        config.compile_html(STARTER_HTML, &mut builder).unwrap();
+        assert!(builder.take_diagnostics().is_empty());
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let dir = std::env::temp_dir().join(format!("tailwind-init-{}", std::process::id()));
+        scaffold(&dir, false, false).unwrap();
+        assert!(!dir.join("index.html").exists());
+        write(dir.join("tailwind.toml"), "minify = true").unwrap();
+        // nothing is written when one of the files exists
+        assert!(scaffold(&dir, true, false).is_err());
+        assert!(!dir.join("index.html").exists());
+        assert_eq!(read_to_string(dir.join("tailwind.toml")).unwrap(), "minify = true");
+        scaffold(&dir, true, true).unwrap();
+        assert_eq!(read_to_string(dir.join("tailwind.toml")).unwrap(), config_template());
+        assert!(dir.join("index.html").exists());
+        remove_dir_all(&dir).unwrap();
+    }
+}
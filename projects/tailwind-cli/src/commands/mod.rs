@@ -1,26 +1,32 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clap::Subcommand;
 
-use tailwind_rs::{CLIConfig, Result};
+use tailwind_rs::Result;
+
+mod init;
 
 #[derive(Subcommand)]
 pub enum TailwindCommands {
+    /// Write a commented `tailwind.toml` with the builtin defaults
     Init {
         #[clap(value_parser, value_name = "DIR")]
         workspace: Option<PathBuf>,
+        /// Also write a starter `index.html`
+        #[clap(long)]
+        html: bool,
+        /// Overwrite the files which already exist
+        #[clap(long)]
+        force: bool,
     },
 }
 
 impl TailwindCommands {
-    pub fn run(&self, config: &CLIConfig) -> Result<()> {
-        let _ = config;
-        println!("?");
+    pub fn run(&self) -> Result<()> {
         match self {
-            Self::Init { workspace } => {
-                println!("'myapp add' was used, name is: {:?}", workspace)
+            Self::Init { workspace, html, force } => {
+                init::scaffold(workspace.as_deref().unwrap_or_else(|| Path::new(".")), *html, *force)
             },
         }
-        Ok(())
     }
 }
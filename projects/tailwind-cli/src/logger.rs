@@ -0,0 +1,38 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Prints the log of the cli, warnings by default, the progress with `-d` and the details with `-dd`
+///
+/// The details of the dependencies are only printed with `-ddd`.
+struct Logger;
+
+static LOGGER: Logger = Logger;
+
+pub fn init(details: u8) {
+    let level = match details {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let own = metadata.level() <= Level::Info || metadata.target().starts_with("tailwind");
+        metadata.level() <= log::max_level() && (own || log::max_level() == LevelFilter::Trace)
+    }
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        match record.level() {
+            Level::Error => eprintln!("error: {}", record.args()),
+            Level::Warn => eprintln!("warning: {}", record.args()),
+            _ => println!("{}", record.args()),
+        }
+    }
+    fn flush(&self) {}
+}
@@ -5,6 +5,7 @@ use clap::{ArgAction, Parser};
 pub use self::{commands::TailwindCommands, run::Mode};
 
 mod commands;
+mod logger;
 mod run;
 
 #[derive(Parser)]
@@ -17,16 +18,33 @@ pub struct TailwindApp {
     /// Sets a custom config file
     #[clap(short, long, value_parser, value_name = "FILE")]
     config: Option<PathBuf>,
+    /// Compile the matching files instead of `**/*.html`, can be repeated
     #[clap(short, long, value_name = "GLOB")]
-    pattern: Option<String>,
+    pattern: Vec<String>,
+    /// Skip the matching files and directories, can be repeated
+    #[clap(long, value_name = "GLOB")]
+    exclude: Vec<String>,
     #[clap(short, long)]
     minify: Option<bool>,
     #[clap(long)]
     obfuscate: Option<bool>,
     #[clap(long)]
     dry_run: bool,
+    /// Write the compiled files into this directory, mirroring the workspace
+    #[clap(short, long, value_name = "DIR")]
+    out_dir: Option<PathBuf>,
+    /// Write one stylesheet for all files, instead of one next to each html file
+    #[clap(long, value_name = "FILE")]
+    css: Option<PathBuf>,
+    /// Add the `--css` stylesheet or an embedded `<style>` to the html files, their classes are kept as written
+    #[clap(long)]
+    in_place: bool,
+    /// Keep running and recompile the files that change
+    #[clap(short, long)]
+    watch: bool,
+    /// Print the progress, `-dd` also prints the skipped files and other details
     #[clap(short, action = ArgAction::Count)]
-    details: usize,
+    details: u8,
     #[clap(long, value_enum)]
     mode: Option<Mode>,
     #[clap(subcommand)]
@@ -35,6 +53,24 @@ pub struct TailwindApp {
 
 fn main() {
     let cli = TailwindApp::parse();
-    let (cfg, mut builder) = cli.build_config();
-    cli.run(&cfg, &mut builder).ok();
+    logger::init(cli.details);
+    // before the config is loaded, so `init --force` can replace a broken one
+    if let Some(command) = &cli.command {
+        if let Err(e) = command.run() {
+            log::error!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+    let (cfg, mut builder) = match cli.build_config() {
+        Ok(o) => o,
+        Err(e) => {
+            log::error!("{}", e);
+            std::process::exit(1);
+        },
+    };
+    if let Err(e) = cli.run(&cfg, &mut builder) {
+        log::error!("{}", e);
+        std::process::exit(1);
+    }
 }
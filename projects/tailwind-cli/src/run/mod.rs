@@ -7,81 +7,128 @@ use std::{
 };
 
 use clap::ValueEnum;
-use glob::glob;
-use tailwind_error::TailwindError;
 
 use tailwind_rs::{CLIConfig, CssInlineMode, Result, TailwindBuilder};
 
 use crate::TailwindApp;
 
+mod output;
+mod sources;
+mod watch;
+
+use self::sources::SourceFilter;
+
 impl TailwindApp {
-    pub fn build_config(&self) -> (CLIConfig, TailwindBuilder) {
-        let mut config = CLIConfig {
-            mode: match self.mode {
-                Some(Mode::Inline) => CssInlineMode::Inline,
-                Some(Mode::Scope) => CssInlineMode::Scoped,
-                Some(Mode::Key) => CssInlineMode::DataKey,
-                Some(Mode::Value) => CssInlineMode::DataValue,
-                _ => CssInlineMode::None,
+    pub fn build_config(&self) -> Result<(CLIConfig, TailwindBuilder)> {
+        // `--config` is relative to where the cli was called, not to the workspace
+        let loaded = match &self.config {
+            Some(path) => {
+                log::info!("Load config from {}", path.display());
+                Some(CLIConfig::load_file(path)?)
             },
-            ..Default::default()
+            None => None,
+        };
+        self.set_workspace()?;
+        let mut config = match loaded {
+            Some(s) => s,
+            None if Path::new("tailwind.toml").is_file() => CLIConfig::load_file(Path::new("tailwind.toml"))?,
+            None => CLIConfig::default(),
+        };
+        config.mode = match self.mode {
+            Some(Mode::Inline) => CssInlineMode::Inline,
+            Some(Mode::Scope) => CssInlineMode::Scoped,
+            Some(Mode::Key) => CssInlineMode::DataKey,
+            Some(Mode::Value) => CssInlineMode::DataValue,
+            _ => CssInlineMode::None,
         };
         if let Some(s) = self.minify {
             config.minify = s;
         }
         config.dry_run = self.dry_run;
-        let builder = config.builder();
         if let Some(s) = self.obfuscate {
             config.obfuscate = s;
         }
-        self.set_workspace().ok();
-        // set_current_dir()
-        (config, builder)
+        if !self.pattern.is_empty() {
+            config.include = self.pattern.clone();
+        }
+        config.exclude.extend(self.exclude.iter().cloned());
+        self.check_in_place(&config)?;
+        let builder = config.try_builder()?;
+        Ok((config, builder))
     }
     fn set_workspace(&self) -> Result<()> {
         if let Some(s) = &self.workspace {
             set_current_dir(s)?;
         }
-        println!("Current workspace: {:?}", current_dir()?);
+        log::info!("Current workspace: {}", current_dir()?.display());
         Ok(())
     }
 }
 
 impl TailwindApp {
     pub fn run(&self, config: &CLIConfig, builder: &mut TailwindBuilder) -> Result<()> {
-        if let Some(c) = &self.command {
-            return c.run(config);
-        };
-
-        for entry in glob("**/*.html")? {
-            let file = entry?;
-            let input = read_to_string(&file)?;
-            let ext = get_extension(&file).ok_or_else(|| TailwindError::runtime_error("no extension"))?;
-            match ext {
-                "html" => {
-                    let (html, css) = match config.compile_html(&input, builder) {
-                        Ok(o) => o,
-                        Err(e) => {
-                            println!("{}", file.display());
-                            println!("{}", e);
-                            continue;
-                        },
-                    };
-                    if config.dry_run {
-                        let html = Byte::from(html.len()).get_appropriate_unit(false);
-                        let css = Byte::from(css.len()).get_appropriate_unit(false);
-                        println!("dry run on {} success", file.display());
-                        println!("HTML size: {}, Css Size: {}", html, css);
-                        continue;
-                    }
-                },
-                _ => {
-                    todo!("unsupported format {}", ext);
-                },
-            }
+        if !self.has_output() && !config.dry_run {
+            log::warn!("Nothing will be written, pass `--out-dir`, `--css` or `--in-place`");
+        }
+        let filter = SourceFilter::new(config)?;
+        for file in self.sources(&filter)? {
+            self.compile_file(config, builder, &file)?;
+        }
+        if !config.dry_run {
+            self.write_stylesheet(config, builder)?;
+        }
+        if self.watch {
+            return self.watch(config, builder, &filter);
         }
         Ok(())
     }
+    /// Compile or scan one source, `true` if the html was written
+    pub(crate) fn compile_file(&self, config: &CLIConfig, builder: &mut TailwindBuilder, file: &Path) -> Result<bool> {
+        let input = read_to_string(file)?;
+        match get_extension(file) {
+            Some("html") => {
+                // every file gets its own css, unless they all share the `--css` stylesheet
+                match self.is_merged() {
+                    true => builder.begin_source(file.to_string_lossy()),
+                    false => builder.clear(),
+                }
+                let compiled = config.compile_html(&input, builder);
+                builder.end_source();
+                for diagnostic in builder.take_diagnostics() {
+                    log::warn!("{}: {}", file.display(), diagnostic);
+                }
+                let (html, css) = match compiled {
+                    Ok(o) => o,
+                    Err(e) => {
+                        log::error!("{}: {}", file.display(), e);
+                        return Ok(false);
+                    },
+                };
+                if config.dry_run {
+                    let html = Byte::from(html.len()).get_appropriate_unit(false);
+                    let css = Byte::from(css.len()).get_appropriate_unit(false);
+                    println!("dry run on {} success", file.display());
+                    println!("HTML size: {}, Css Size: {}", html, css);
+                    return Ok(false);
+                }
+                log::info!("compiled {}", file.display());
+                self.write_html(file, &input, &html, &css)?;
+                Ok(true)
+            },
+            // rust, jsx, vue, markdown, templates... only feed the shared stylesheet
+            _ if self.is_merged() => {
+                builder.begin_source(file.to_string_lossy());
+                let found = builder.scan(&input);
+                builder.end_source();
+                log::info!("scanned {}, {} classes", file.display(), found);
+                Ok(false)
+            },
+            _ => {
+                log::warn!("{}: only html files are compiled without `--css`", file.display());
+                Ok(false)
+            },
+        }
+    }
 }
 
 fn get_extension(path: &Path) -> Option<&str> {
@@ -0,0 +1,204 @@
+use std::{
+    fs::{create_dir_all, write},
+    path::{absolute, Component, Path, PathBuf},
+};
+
+use tailwind_rs::{CLIConfig, CssInlineMode, Result, TailwindBuilder, TailwindError};
+
+use crate::TailwindApp;
+
+/// Marks the tags written by the cli, so that a rewrite replaces them instead of stacking up
+const MARKER: &str = "data-tailwind";
+
+impl TailwindApp {
+    /// Nothing is written unless one of `--out-dir`, `--css` or `--in-place` is given
+    pub fn has_output(&self) -> bool {
+        self.out_dir.is_some() || self.css.is_some() || self.in_place
+    }
+    /// The classes of an `--in-place` source must stay readable, so that the next run compiles them again
+    pub fn check_in_place(&self, config: &CLIConfig) -> Result<()> {
+        if self.in_place && (config.obfuscate || config.mode != CssInlineMode::None) {
+            return Err(TailwindError::runtime_error("`--in-place` keeps the classes of the sources, it can't be combined with `--obfuscate` or `--mode`"));
+        }
+        Ok(())
+    }
+    /// All files share one stylesheet, written once every file is compiled
+    pub fn is_merged(&self) -> bool {
+        self.css.is_some()
+    }
+    /// Files compiled into `--out-dir` must not be picked up as sources again
+    pub fn is_output(&self, file: &Path) -> bool {
+        match (&self.out_dir, file.canonicalize()) {
+            (Some(dir), Ok(file)) => dir.canonicalize().is_ok_and(|dir| file.starts_with(dir)),
+            _ => false,
+        }
+    }
+    /// Write the compiled html, and its css unless all files share the `--css` stylesheet
+    ///
+    /// `--in-place` only adds the stylesheet to the `input`, the classes of the source are kept as written.
+    pub fn write_html(&self, file: &Path, input: &str, html: &str, css: &str) -> Result<()> {
+        if self.in_place {
+            let input = inject_head(input, &self.stylesheet_tag(file, css));
+            write(file, input)?;
+        }
+        if let Some(dir) = &self.out_dir {
+            let target = dir.join(file);
+            if let Some(parent) = target.parent() {
+                create_dir_all(parent)?;
+            }
+            let html = match &self.css {
+                Some(_) => inject_head(html, &self.stylesheet_tag(&target, css)),
+                None => {
+                    let sheet = target.with_extension("css");
+                    write(&sheet, css)?;
+                    inject_head(html, &link_tag(&relative_path(&sheet, &target)))
+                },
+            };
+            write(&target, html)?;
+        }
+        Ok(())
+    }
+    /// Write the `--css` stylesheet shared by all files
+    pub fn write_stylesheet(&self, config: &CLIConfig, builder: &TailwindBuilder) -> Result<()> {
+        if let Some(path) = &self.css {
+            if let Some(parent) = path.parent() {
+                create_dir_all(parent)?;
+            }
+            let css = config.compile_css(&builder.bundle()?)?;
+            write(path, css)?;
+            log::info!("write stylesheet to {}", path.display());
+        }
+        Ok(())
+    }
+    /// `<link>` the `--css` stylesheet if there is one, embed a `<style>` otherwise
+    fn stylesheet_tag(&self, html: &Path, css: &str) -> String {
+        match &self.css {
+            Some(sheet) => link_tag(&relative_path(sheet, html)),
+            None => format!("<style {}>{}</style>", MARKER, css),
+        }
+    }
+}
+
+fn link_tag(href: &str) -> String {
+    format!("<link rel=\"stylesheet\" href=\"{}\" {}>", href, MARKER)
+}
+
+/// Put the tag at the end of `<head>`, replacing the one of a previous run
+fn inject_head(html: &str, tag: &str) -> String {
+    let mut html = remove_marked(html);
+    match html.find("</head>") {
+        Some(i) => html.insert_str(i, tag),
+        None => html.insert_str(0, tag),
+    }
+    html
+}
+
+/// Remove the `<link ... data-tailwind>` and `<style data-tailwind>...</style>` tags from the `<head>`
+fn remove_marked(html: &str) -> String {
+    let mut out = html.to_string();
+    let mut i = 0;
+    loop {
+        if let Some(len) = marked_tag(&out[i..]) {
+            out.replace_range(i..i + len, "");
+            continue;
+        }
+        // a document without `<head>` got the tag at its start
+        let head = out.find("</head>").unwrap_or(0);
+        match out.get(i + 1..head).and_then(|s| s.find('<')) {
+            Some(n) => i += 1 + n,
+            None => break out,
+        }
+    }
+}
+
+/// The length of the marked tag at the start of `html`, as written by [`link_tag`] or `stylesheet_tag`
+fn marked_tag(html: &str) -> Option<usize> {
+    let open = html.find('>')? + 1;
+    let tag = &html[..open];
+    if tag == format!("<style {}>", MARKER) {
+        return html.find("</style>").map(|end| end + "</style>".len());
+    }
+    match tag.starts_with("<link ") && tag.ends_with(&format!(" {}>", MARKER)) {
+        true => Some(open),
+        false => None,
+    }
+}
+
+/// The path of `target` seen from the directory of `file`, with `/` as separator
+///
+/// An absolute path is compared with the absolute path of the other, and kept as is when they share no root.
+fn relative_path(target: &Path, file: &Path) -> String {
+    let (target, file) = match target.is_absolute() || file.is_absolute() {
+        true => match (absolute(target), absolute(file)) {
+            (Ok(target), Ok(file)) => (target, file),
+            _ => return target.to_string_lossy().to_string(),
+        },
+        false => (target.to_path_buf(), file.to_path_buf()),
+    };
+    let normal = |p: &Path| -> Vec<PathBuf> {
+        p.components().filter(|c| !matches!(c, Component::CurDir)).map(|c| PathBuf::from(c.as_os_str())).collect()
+    };
+    let parts = normal(&target);
+    let base = normal(file.parent().unwrap_or_else(|| Path::new("")));
+    let common = parts.iter().zip(base.iter()).take_while(|(a, b)| a == b).count();
+    if common == 0 && target.is_absolute() {
+        return target.to_string_lossy().to_string();
+    }
+    let mut out: Vec<String> = vec!["..".to_string(); base.len() - common];
+    out.extend(parts[common..].iter().map(|p| p.to_string_lossy().to_string()));
+    out.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn test_inject() {
+        let html = "<html><head><title>a</title></head><body></body></html>";
+        let once = inject_head(html, &link_tag("app.css"));
+        assert_eq!(once, "<html><head><title>a</title><link rel=\"stylesheet\" href=\"app.css\" data-tailwind></head><body></body></html>");
+        let twice = inject_head(&once, "<style data-tailwind>.p-2{padding:.5rem}</style>");
+        assert_eq!(twice, "<html><head><title>a</title><style data-tailwind>.p-2{padding:.5rem}</style></head><body></body></html>");
+        assert_eq!(remove_marked(&twice), html);
+        assert_eq!(relative_path(Path::new("dist/app.css"), Path::new("dist/blog/index.html")), "../app.css");
+        assert_eq!(relative_path(Path::new("./app.css"), Path::new("index.html")), "app.css");
+        let cwd = std::env::current_dir().unwrap();
+        assert_eq!(relative_path(&cwd.join("dist/app.css"), Path::new("dist/blog/index.html")), "../app.css");
+        assert_eq!(relative_path(Path::new("/srv/app.css"), Path::new("/srv/site/index.html")), "../app.css");
+    }
+
+    #[test]
+    fn test_in_place() {
+        let dir = std::env::temp_dir().join(format!("tailwind-in-place-{}", std::process::id()));
+        create_dir_all(&dir).unwrap();
+        let file = dir.join("index.html");
+        let body = r#"<body><p class="p-4 hover:(m-2 underline) bg-red-500">a</p></body>"#;
+        write(&file, format!("<html><head></head>{}</html>", body)).unwrap();
+        let app = TailwindApp::parse_from(["tailwind", "--in-place"]);
+        let config = CLIConfig::default();
+        let mut builder = config.builder();
+        for _ in 0..2 {
+            app.compile_file(&config, &mut builder, &file).unwrap();
+            let html = std::fs::read_to_string(&file).unwrap();
+            assert!(html.ends_with(&format!("</style></head>{}</html>", body)));
+            assert!(html.contains(".hover\\:m-2:hover") && html.matches("<style").count() == 1);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+        let config = CLIConfig { obfuscate: true, ..Default::default() };
+        assert!(app.check_in_place(&config).is_err());
+        let config = CLIConfig { mode: CssInlineMode::Inline, ..Default::default() };
+        assert!(app.check_in_place(&config).is_err());
+    }
+
+    #[test]
+    fn test_marker_in_body() {
+        let html = "<html><head><meta name=\"data-tailwind\"></head><body><p>Set data-tailwind on the link</p><div data-tailwind></div></body></html>";
+        let once = inject_head(html, "<style data-tailwind>.p-2{padding:.5rem}</style>");
+        assert_eq!(remove_marked(&once), html);
+        assert_eq!(inject_head(&once, "<style data-tailwind></style>").matches("<style").count(), 1);
+        let headless = inject_head("<p>data-tailwind</p>", &link_tag("app.css"));
+        assert_eq!(remove_marked(&headless), "<p>data-tailwind</p>");
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    fs::read_dir,
+    path::{Path, PathBuf},
+};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use tailwind_rs::{CLIConfig, Result};
+
+use crate::TailwindApp;
+
+/// Directories which never contain sources
+const SKIPPED: [&str; 4] = ["**/node_modules", "**/target", "**/dist", "**/.git"];
+
+/// Decides which files of the workspace are compiled, from the `include` and `exclude` globs
+pub struct SourceFilter {
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl SourceFilter {
+    pub fn new(config: &CLIConfig) -> Result<Self> {
+        let include = match config.include.is_empty() {
+            true => vec!["**/*.html".to_string()],
+            false => config.include.clone(),
+        };
+        let exclude = SKIPPED.iter().map(|s| s.to_string()).chain(config.exclude.iter().cloned());
+        log::debug!("include: {:?}", include);
+        Ok(Self { include: glob_set(include)?, exclude: glob_set(exclude)? })
+    }
+    pub fn is_included(&self, path: &Path) -> bool {
+        self.include.is_match(path)
+    }
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        self.exclude.is_match(path)
+    }
+}
+
+fn glob_set(globs: impl IntoIterator<Item = String>) -> Result<GlobSet> {
+    let mut set = GlobSetBuilder::new();
+    for glob in globs {
+        set.add(Glob::new(&glob)?);
+    }
+    Ok(set.build()?)
+}
+
+impl TailwindApp {
+    /// The source files of the workspace, without the excluded ones and the ones written by the cli
+    pub(super) fn sources(&self, filter: &SourceFilter) -> Result<Vec<PathBuf>> {
+        let mut files = vec![];
+        self.walk(filter, Path::new(""), &mut files)?;
+        files.sort();
+        Ok(files)
+    }
+    /// Excluded directories are not entered, so that `node_modules` costs nothing
+    fn walk(&self, filter: &SourceFilter, dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+        let read = match dir.as_os_str().is_empty() {
+            true => Path::new("."),
+            false => dir,
+        };
+        for entry in read_dir(read)? {
+            let entry = entry?;
+            let path = dir.join(entry.file_name());
+            if filter.is_excluded(&path) || self.is_output(&path) {
+                log::debug!("skip {}", path.display());
+                continue;
+            }
+            let kind = entry.file_type()?;
+            if kind.is_dir() {
+                self.walk(filter, &path, files)?;
+            }
+            else if kind.is_file() && filter.is_included(&path) {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let config = CLIConfig {
+            include: vec!["**/*.html".to_string(), "src/**/*.rs".to_string()],
+            exclude: vec!["drafts/**".to_string()],
+            ..Default::default()
+        };
+        let filter = SourceFilter::new(&config).unwrap();
+        assert!(filter.is_included(Path::new("index.html")));
+        assert!(filter.is_included(Path::new("blog/post.html")));
+        assert!(filter.is_included(Path::new("src/app.rs")));
+        assert!(!filter.is_included(Path::new("app.rs")));
+        assert!(filter.is_excluded(Path::new("node_modules")));
+        assert!(filter.is_excluded(Path::new("web/node_modules")));
+        assert!(filter.is_excluded(Path::new("dist")));
+        assert!(filter.is_excluded(Path::new("drafts/a.html")));
+        assert!(!filter.is_excluded(Path::new("distant/a.html")));
+        let filter = SourceFilter::new(&CLIConfig::default()).unwrap();
+        assert!(filter.is_included(Path::new("index.html")));
+        assert!(!filter.is_included(Path::new("index.css")));
+    }
+}
@@ -0,0 +1,71 @@
+use std::{
+    collections::HashMap,
+    fs::metadata,
+    path::{Path, PathBuf},
+    thread::sleep,
+    time::{Duration, SystemTime},
+};
+
+use tailwind_rs::{CLIConfig, Result, TailwindBuilder};
+
+use super::SourceFilter;
+use crate::TailwindApp;
+
+/// How often the workspace is polled for changes
+const INTERVAL: Duration = Duration::from_millis(500);
+
+impl TailwindApp {
+    /// Recompile the files changed since the last poll, until the process is killed
+    ///
+    /// With `--css` the classes of a changed file replace the ones it had before,
+    /// and the classes of a deleted file are dropped unless another file still uses them.
+    pub(super) fn watch(&self, config: &CLIConfig, builder: &mut TailwindBuilder, filter: &SourceFilter) -> Result<()> {
+        println!("Watching for changes, press Ctrl-C to stop");
+        let mut seen = self.snapshot(filter)?;
+        loop {
+            sleep(INTERVAL);
+            // taken before compiling, so a file saved during the rebuild is compiled on the next poll
+            let mut now = self.snapshot(filter)?;
+            let changed: Vec<PathBuf> =
+                now.iter().filter(|(file, time)| seen.get(*file) != Some(*time)).map(|(file, _)| file.clone()).collect();
+            let removed: Vec<_> = seen.keys().filter(|file| !now.contains_key(*file)).collect();
+            if changed.is_empty() && removed.is_empty() {
+                continue;
+            }
+            for file in removed {
+                builder.remove_source(&file.to_string_lossy());
+                log::info!("removed {}", file.display());
+            }
+            for file in &changed {
+                log::info!("changed {}", file.display());
+                match self.compile_file(config, builder, file) {
+                    // `--in-place` rewrote the source, take its new time so it is not compiled again
+                    Ok(true) if self.in_place => {
+                        if let (Some(time), Ok(written)) = (now.get_mut(file), modified(file)) {
+                            *time = written;
+                        }
+                    },
+                    Ok(_) => {},
+                    Err(e) => log::error!("{}: {}", file.display(), e),
+                }
+            }
+            if !config.dry_run {
+                self.write_stylesheet(config, builder)?;
+            }
+            seen = now;
+        }
+    }
+    fn snapshot(&self, filter: &SourceFilter) -> Result<HashMap<PathBuf, SystemTime>> {
+        let mut times = HashMap::new();
+        for file in self.sources(filter)? {
+            if let Ok(time) = modified(&file) {
+                times.insert(file, time);
+            }
+        }
+        Ok(times)
+    }
+}
+
+fn modified(file: &Path) -> std::io::Result<SystemTime> {
+    metadata(file)?.modified()
+}
@@ -0,0 +1,8 @@
+use crate::TailwindError;
+use toml::de::Error;
+
+impl From<Error> for TailwindError {
+    fn from(e: Error) -> Self {
+        TailwindError::syntax_error(e.to_string())
+    }
+}
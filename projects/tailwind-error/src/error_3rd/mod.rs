@@ -28,3 +28,5 @@ mod for_serde_json;
 mod for_time;
 #[cfg(feature = "tl")]
 mod for_tl;
+#[cfg(feature = "toml")]
+mod for_toml;
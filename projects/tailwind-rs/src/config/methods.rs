@@ -0,0 +1,93 @@
+use super::*;
+use crate::{Result, TailwindError};
+use std::{fs::read_to_string, path::Path};
+use tailwind_css_fixes::{PreflightSystem, TailwindBuilder};
+
+impl CLIConfig {
+    /// Load the config from a `.toml`, `.json` or `.css` file
+    pub fn load_file(path: &Path) -> Result<Self> {
+        let loaded = read_to_string(path).map_err(TailwindError::from).and_then(|text| {
+            match path.extension().and_then(|s| s.to_str()) {
+                Some("json") => Self::load_json(&text),
+                Some("css") => Self::load_css(&text),
+                _ => Self::load_toml(&text),
+            }
+        });
+        loaded.map_err(|mut e| {
+            e.file = Some(path.to_path_buf());
+            e
+        })
+    }
+    /// Load the config from the text of a `tailwind.toml` file
+    pub fn load_toml(text: &str) -> Result<Self> {
+        Self::from_file(toml::from_str(text)?)
+    }
+    /// Load the config from the text of a `tailwind.json` file
+    pub fn load_json(text: &str) -> Result<Self> {
+        Self::from_file(serde_json::from_str(text)?)
+    }
+    /// Load the theme from the `@theme { }` blocks of a tailwind v4 stylesheet
+    pub fn load_css(text: &str) -> Result<Self> {
+        Self::from_file(ConfigFile { theme: ThemeConfig::parse_css(text)?, ..Default::default() })
+    }
+    fn from_file(file: ConfigFile) -> Result<Self> {
+        let config = Self {
+            minify: file.minify.unwrap_or_default(),
+            obfuscate: file.obfuscate.unwrap_or_default(),
+            theme: file.theme,
+            preflight: file.preflight,
+            include: file.include,
+            exclude: file.exclude,
+            ..Default::default()
+        };
+        // report invalid values early, so that `builder` never fails
+        config.try_builder()?;
+        Ok(config)
+    }
+    /// Create a builder with the configured theme and preflight
+    pub fn builder(&self) -> TailwindBuilder {
+        match self.try_builder() {
+            Ok(o) => o,
+            Err(e) => {
+                log::error!("{}", e);
+                TailwindBuilder::default()
+            },
+        }
+    }
+    /// Create a builder with the configured theme and preflight, fails if the theme is invalid
+    pub fn try_builder(&self) -> Result<TailwindBuilder> {
+        let mut builder = TailwindBuilder::default();
+        builder.apply_theme(&self.theme)?;
+        self.preflight.apply(&mut builder.preflight);
+        Ok(builder)
+    }
+}
+
+impl PreflightConfig {
+    /// Overwrite the flags which are set in the config
+    pub fn apply(&self, preflight: &mut PreflightSystem) {
+        let flags = [
+            (self.disable, &mut preflight.disable),
+            (self.default_vars, &mut preflight.default_vars),
+            (self.global_reset, &mut preflight.global_reset),
+            (self.html_base, &mut preflight.html_base),
+            (self.unstyle_headings, &mut preflight.unstyle_headings),
+            (self.unstyle_links, &mut preflight.unstyle_links),
+            (self.unstyle_lists, &mut preflight.unstyle_lists),
+            (self.block_level_media, &mut preflight.block_level_media),
+            (self.reset_tables, &mut preflight.reset_tables),
+            (self.reset_forms, &mut preflight.reset_forms),
+            (self.hidden_attribute, &mut preflight.hidden_attribute),
+            (self.specific_extras, &mut preflight.specific_extras),
+            (self.compatibility_fixes, &mut preflight.compatibility_fixes),
+        ];
+        for (value, flag) in flags {
+            if let Some(value) = value {
+                *flag = value;
+            }
+        }
+        if let Some(custom) = &self.custom {
+            preflight.add_custom(custom);
+        }
+    }
+}
@@ -1,5 +1,8 @@
+use serde::Deserialize;
 use std::collections::HashSet;
-use tailwind_css_fixes::CssInlineMode;
+use tailwind_css_fixes::{CssInlineMode, ThemeConfig};
+
+mod methods;
 
 /// The `Tailwind` configuration.
 #[derive(Debug, Default)]
@@ -10,6 +13,13 @@ pub struct CLIConfig {
     pub mode: CssInlineMode,
     pub unused_symbols: HashSet<String>,
     pub html: HtmlConfig,
+    /// Overrides of the builtin theme, see [`ThemeConfig`]
+    pub theme: ThemeConfig,
+    pub preflight: PreflightConfig,
+    /// Globs of the source files, relative to the workspace, `**/*.html` if empty
+    pub include: Vec<String>,
+    /// Globs of the files and directories to skip, on top of `node_modules`, `target` and `dist`
+    pub exclude: Vec<String>,
 }
 
 /// The `html` configuration.
@@ -18,6 +28,39 @@ pub struct HtmlConfig {
     pub include_attributes: HashSet<String>,
 }
 
+/// The `[preflight]` section of a config file, unset flags keep the builtin value.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PreflightConfig {
+    pub disable: Option<bool>,
+    pub default_vars: Option<bool>,
+    pub global_reset: Option<bool>,
+    pub html_base: Option<bool>,
+    pub unstyle_headings: Option<bool>,
+    pub unstyle_links: Option<bool>,
+    pub unstyle_lists: Option<bool>,
+    pub block_level_media: Option<bool>,
+    pub reset_tables: Option<bool>,
+    pub reset_forms: Option<bool>,
+    pub hidden_attribute: Option<bool>,
+    pub specific_extras: Option<bool>,
+    pub compatibility_fixes: Option<bool>,
+    /// Custom css prepended to the preflight styles
+    pub custom: Option<String>,
+}
+
+/// The content of a `tailwind.toml` or `tailwind.json` file.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    minify: Option<bool>,
+    obfuscate: Option<bool>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    preflight: PreflightConfig,
+    theme: ThemeConfig,
+}
+
 impl Default for HtmlConfig {
     fn default() -> Self {
         let mut include_attributes = HashSet::default();
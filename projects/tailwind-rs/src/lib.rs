@@ -8,14 +8,14 @@
 #[cfg(doctest)]
 pub struct ReadmeDoctests;
 
-pub use self::config::CLIConfig;
+pub use self::config::{CLIConfig, PreflightConfig};
 pub use tailwind_error::{Result, TailwindError};
 
 mod config;
 mod processor;
 mod support;
 
-pub use tailwind_css_fixes::{CssInlineMode, TailwindBuilder};
+pub use tailwind_css_fixes::{CssInlineMode, TailwindBuilder, TailwindDiagnostic};
 
 #[cfg(test)]
 mod lib_tests {
@@ -35,6 +35,6 @@ mod lib_tests {
         let (html, css) = config.compile_html(&input_html, &mut builder).unwrap();
 
         assert_eq!(html, input_html);
-        assert_eq!(css, ".border-red-500 {\n  border-color: #ef4444;\n}\n\n.p-2 {\n  padding: .5rem;\n}\n");
+        assert_eq!(css, ".border-red-500 {\n  border-color: #fb2c36;\n}\n\n.p-2 {\n  padding: .5rem;\n}\n");
     }
 }
@@ -5,9 +5,6 @@ use tl::{parse, Bytes, Node, ParserOptions};
 use crate::{config::HtmlConfig, CLIConfig, Result};
 
 impl CLIConfig {
-    pub fn builder(&self) -> TailwindBuilder {
-        TailwindBuilder::default()
-    }
     /// Compile html and css
     pub fn compile_html(&self, input: &str, tw: &mut TailwindBuilder) -> Result<(String, String)> {
         let html = match self.mode {
@@ -0,0 +1,36 @@
+use super::*;
+use tailwind_css_fixes::DarkMode;
+
+#[test]
+fn test_dark_media() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    builder.dark_mode = DarkMode::Media;
+    let (html, css) = config.compile_html(include_str!("dark.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/dark/dark.media.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("dark.traced.html"));
+    assert_eq!(css, include_str!("dark.media.css"));
+}
+
+#[test]
+fn test_dark_class() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    builder.dark_mode = DarkMode::Class;
+    let (html, css) = config.compile_html(include_str!("dark.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/dark/dark.class.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("dark.traced.html"));
+    assert_eq!(css, include_str!("dark.class.css"));
+}
+
+#[test]
+fn test_dark_selector() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    builder.dark_mode = DarkMode::Selector("[data-theme=dark]".to_string());
+    let (html, css) = config.compile_html(include_str!("dark.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/dark/dark.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/dark/dark.selector.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("dark.traced.html"));
+    assert_eq!(css, include_str!("dark.selector.css"));
+}
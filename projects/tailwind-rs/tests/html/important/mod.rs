@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn test_important_trace() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("important.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/important/important.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/important/important.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("important.traced.html"));
+    assert_eq!(css, include_str!("important.traced.css"));
+}
+
+#[test]
+fn test_important_inline() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::Inline;
+    let (html, css) = config.compile_html(include_str!("important.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/important/important.inline.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/important/important.inline.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("important.inline.html"));
+    assert_eq!(css, include_str!("important.inline.css"));
+}
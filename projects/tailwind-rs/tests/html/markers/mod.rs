@@ -0,0 +1,35 @@
+use super::*;
+
+#[test]
+fn test_markers_trace() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("markers.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/markers/markers.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/markers/markers.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("markers.traced.html"));
+    assert_eq!(css, include_str!("markers.traced.css"));
+}
+
+#[test]
+fn test_markers_obfuscate() {
+    let (mut config, mut builder) = pre_config();
+    config.obfuscate = true;
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("markers.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/markers/markers.obfuscate.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/markers/markers.obfuscate.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("markers.obfuscate.html"));
+    assert_eq!(css, include_str!("markers.obfuscate.css"));
+}
+
+#[test]
+fn test_markers_inline() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::Inline;
+    let (html, css) = config.compile_html(include_str!("markers.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/markers/markers.inline.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/markers/markers.inline.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("markers.inline.html"));
+    assert_eq!(css, include_str!("markers.inline.css"));
+}
@@ -10,19 +10,26 @@ mod accessibility;
 mod arbitrary;
 mod background;
 mod border;
+mod dark;
 mod effect;
 mod filter;
 mod flex;
 mod grouped;
+mod important;
 mod interactivity;
 mod layout;
+mod markers;
+mod prose;
 mod sizing;
 mod spacing;
 mod svg;
 mod table;
+mod theme;
 mod transform;
 mod transition;
+mod responsive;
 mod typography;
+mod variants;
 
 fn pre_config() -> (CLIConfig, TailwindBuilder) {
     let mut config = CLIConfig::default();
@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+fn test_responsive_trace() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("responsive.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/responsive/responsive.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/responsive/responsive.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("responsive.traced.html"));
+    assert_eq!(css, include_str!("responsive.traced.css"));
+}
+
+#[test]
+fn test_responsive_inline() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::Inline;
+    let (html, css) = config.compile_html(include_str!("responsive.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/responsive/responsive.inline.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/responsive/responsive.inline.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("responsive.inline.html"));
+    assert_eq!(css, include_str!("responsive.inline.css"));
+}
+
+#[test]
+fn test_empty_screen() {
+    let (_, mut builder) = pre_config();
+    assert!(builder.trace("max-[0px]:p-1", false).is_err());
+    assert!(builder.trace("max-[1px]:p-1", false).is_ok());
+    builder.screens.register("zero".to_string(), 0);
+    assert!(builder.trace("max-zero:p-1", false).is_err());
+}
@@ -0,0 +1,55 @@
+use super::*;
+use std::path::Path;
+
+fn load_config(path: &str) -> (CLIConfig, TailwindBuilder) {
+    let mut config = CLIConfig::load_file(Path::new(path)).unwrap();
+    let mut builder = config.builder();
+    config.minify = false;
+    config.mode = CssInlineMode::None;
+    builder.preflight.disable = true;
+    (config, builder)
+}
+
+#[test]
+fn test_theme_toml() {
+    let (config, mut builder) = load_config("src/config/tailwind.toml");
+    assert_eq!(config.include, ["**/*.html"]);
+    assert_eq!(config.exclude, ["drafts/**"]);
+    let (html, css) = config.compile_html(include_str!("theme.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/theme/theme.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/theme/theme.toml.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("theme.traced.html"));
+    assert_eq!(css, include_str!("theme.toml.css"));
+}
+
+#[test]
+fn test_theme_json() {
+    let (config, mut builder) = load_config("tests/html/theme/theme.json");
+    let (html, css) = config.compile_html(include_str!("theme.html"), &mut builder).unwrap();
+    // the json theme replaces the colors and font families, and has no `gutter` step, `tiny` size,
+    // `loose` letter spacing or `soft` and `glow` shadows
+    let rejected: Vec<_> = builder.take_diagnostics().into_iter().map(|d| d.class).collect();
+    assert_eq!(rejected, ["px-gutter", "tracking-loose", "font-sans", "text-tiny", "text-primary", "shadow-soft", "drop-shadow-glow"]);
+    std::fs::write("tests/html/theme/theme.json.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/theme/theme.json.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("theme.json.html"));
+    assert_eq!(css, include_str!("theme.json.css"));
+}
+
+#[test]
+fn test_theme_css() {
+    let (config, mut builder) = load_config("tests/html/theme/theme.css");
+    let (html, css) = config.compile_html(include_str!("theme.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/theme/theme.css.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/theme/theme.css.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("theme.css.html"));
+    assert_eq!(css, include_str!("theme.css.css"));
+}
+
+#[test]
+fn test_theme_invalid() {
+    assert!(CLIConfig::load_toml("[theme.colors]\nbrand = { 500 = \"not a color\" }").is_err());
+    assert!(CLIConfig::load_toml("[theme.screens]\nmd = \"48rem\"").is_err());
+    assert!(CLIConfig::load_css("@theme { --breakpoint-md: wide; }").is_err());
+    assert!(CLIConfig::load_json("{ \"theme\": { \"letterSpacing\": { \"wide\": \"1px\" } } }").is_err());
+}
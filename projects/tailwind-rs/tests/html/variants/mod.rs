@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn test_variants_trace() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("variants.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/variants/variants.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/variants/variants.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("variants.traced.html"));
+    assert_eq!(css, include_str!("variants.traced.css"));
+}
+
+#[test]
+fn test_variants_inline() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::Inline;
+    let (html, css) = config.compile_html(include_str!("variants.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/variants/variants.inline.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/variants/variants.inline.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("variants.inline.html"));
+    assert_eq!(css, include_str!("variants.inline.css"));
+}
@@ -14,6 +14,9 @@ impl Display for TailwindBackgroundColor {
 }
 
 impl TailwindInstance for TailwindBackgroundColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
             "background-color" => self.color.get_properties(ctx)
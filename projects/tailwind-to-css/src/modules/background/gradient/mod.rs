@@ -37,6 +37,9 @@ impl Display for TailwindTo {
     }
 }
 impl TailwindInstance for TailwindFrom {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let color = self.color.get_properties(ctx);
         css_attributes! {
@@ -46,6 +49,9 @@ impl TailwindInstance for TailwindFrom {
     }
 }
 impl TailwindInstance for TailwindVia {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let color = self.color.get_properties(ctx);
         css_attributes! {
@@ -54,6 +60,9 @@ impl TailwindInstance for TailwindVia {
     }
 }
 impl TailwindInstance for TailwindTo {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let color = self.color.get_properties(ctx);
         css_attributes! {
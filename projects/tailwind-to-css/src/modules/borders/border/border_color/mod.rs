@@ -15,6 +15,9 @@ impl Display for TailwindBorderColor {
 }
 
 impl TailwindInstance for TailwindBorderColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
             "border-color" => self.color.get_properties(ctx)
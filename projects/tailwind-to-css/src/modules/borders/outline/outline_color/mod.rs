@@ -15,6 +15,9 @@ impl Display for TailwindOutlineColor {
 }
 
 impl TailwindInstance for TailwindOutlineColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
             "outline-color" => self.color.get_properties(ctx)
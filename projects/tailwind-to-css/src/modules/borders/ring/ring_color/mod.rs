@@ -15,6 +15,9 @@ impl Display for TailwindRingColor {
 }
 
 impl TailwindInstance for TailwindRingColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
             "--tw-ring-color" => self.color.get_properties(ctx)
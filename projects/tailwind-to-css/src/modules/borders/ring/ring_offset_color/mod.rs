@@ -15,6 +15,9 @@ impl Display for TailwindRingOffsetColor {
 }
 
 impl TailwindInstance for TailwindRingOffsetColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
             "--tw-ring-offset-color" => self.color.get_properties(ctx),
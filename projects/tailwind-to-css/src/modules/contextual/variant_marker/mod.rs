@@ -1,36 +1,62 @@
-use crate::{CssAttributes, TailwindBuilder, TailwindInstance, TailwindArbitrary, Result, StandardValue};
+use crate::{syntax_error, CssAttributes, Result, TailwindArbitrary, TailwindBuilder, TailwindInstance};
 use std::{
     collections::BTreeSet,
     fmt::{Display, Formatter},
 };
 
 #[doc=include_str!("readme.md")]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TailwindVariantMarker {
-    kind: StandardValue,
+    /// `peer` or `group`
+    peer: bool,
+    /// `group/sidebar`
+    name: Option<String>,
 }
 
-
-
 impl Display for TailwindVariantMarker {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // Return name as traced name
-        write!(f, "{}", self.kind)
+        match self.peer {
+            true => write!(f, "peer")?,
+            false => write!(f, "group")?,
+        }
+        match &self.name {
+            Some(s) => write!(f, "/{}", s),
+            None => Ok(()),
+        }
     }
 }
 
 impl TailwindInstance for TailwindVariantMarker {
+    /// The marker is referenced by the selectors of other elements, it must stay in the class list.
+    fn inlineable(&self) -> bool {
+        false
+    }
+    /// The marker is referenced by name, so it can not be renamed.
+    fn obfuscatable(&self) -> bool {
+        false
+    }
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         // Give empty css properties
         CssAttributes::default()
-        // !todo: this will cause the obfuscated classnames to be indentical
-        // !todo: During obfuscation mode, the variants need to know which classnames are the variant markers...
     }
 }
 
 impl TailwindVariantMarker {
+    /// `group`, `peer`, `group/sidebar`
+    pub fn new(peer: bool, name: Option<String>) -> Self {
+        Self { peer, name }
+    }
     pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
-        Ok(Self { kind: StandardValue::parser("variant-marker", &Self::check_valid)(pattern, arbitrary)? })
+        let marker = pattern.join("-");
+        let (mode, name) = match marker.split_once('/') {
+            Some((mode, name)) => (mode, Some(name)),
+            None => (marker.as_str(), None),
+        };
+        if !Self::check_valid(mode) || arbitrary.is_some() || name == Some("") {
+            return syntax_error!("Unknown variant marker: {}", marker);
+        }
+        Ok(Self::new(mode == "peer", name.map(|s| s.to_string())))
     }
 
     pub fn check_valid(mode: &str) -> bool {
@@ -38,4 +64,8 @@ impl TailwindVariantMarker {
         let set = BTreeSet::from_iter(vec!["peer", "group"]);
         set.contains(mode)
     }
-}
\ No newline at end of file
+    /// The css selector of the marked element, e.g. `.group\/sidebar`
+    pub fn get_selector(&self) -> String {
+        format!(".{}", self.to_string().replace('/', "\\/"))
+    }
+}
@@ -21,11 +21,22 @@ impl Display for TailwindShadow {
 }
 
 impl TailwindInstance for TailwindShadow {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        match &self.kind {
+            StandardValue::Keyword(s) if self.drop.0 && ctx.effects.get_drop_shadow(s).is_none() => {
+                syntax_error!("Unknown drop shadow: {}", s)
+            },
+            StandardValue::Keyword(s) if !self.drop.0 && ctx.effects.get_box_shadow(s).is_none() => {
+                syntax_error!("Unknown shadow: {}", s)
+            },
+            _ => Ok(()),
+        }
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let shadow = match &self.kind {
             // TODO: resolve color
-            StandardValue::Keyword(s) if self.drop.0 => ctx.effects.get_drop_shadow(s),
-            StandardValue::Keyword(s) => ctx.effects.get_box_shadow(s),
+            StandardValue::Keyword(s) if self.drop.0 => ctx.effects.get_drop_shadow(s).unwrap_or_default(),
+            StandardValue::Keyword(s) => ctx.effects.get_box_shadow(s).unwrap_or_default(),
             StandardValue::Arbitrary(s) => s.get_properties(),
         };
         self.drop.get_shadow(shadow)
@@ -15,6 +15,9 @@ impl Display for TailwindShadowColor {
 }
 
 impl TailwindInstance for TailwindShadowColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
             "--tw-shadow-color" => self.color.get_properties(ctx),
@@ -28,7 +28,9 @@ impl TailwindContent {
             // https://tailwindcss.com/docs/content
             _ => {
                 let s = pattern.join("-");
-                debug_assert!(Self::check_valid(&s));
+                if !Self::check_valid(&s) {
+                    return syntax_error!("Unknown content instructions: {}", s);
+                }
                 TailwindContent::from(s).boxed()
             },
         };
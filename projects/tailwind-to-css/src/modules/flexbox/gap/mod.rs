@@ -1,32 +1,35 @@
 use super::*;
-use crate::AxisXY;
+use crate::{AxisXY, SpacingSize};
 
 #[doc=include_str!("readme.md")]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct TailwindGap {
-    size: LengthUnit,
+    size: SpacingSize,
     axis: AxisXY,
 }
 
 impl Display for TailwindGap {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self.axis {
-            AxisXY::N => write!(f, "gap-{}", self.size.get_class_arbitrary()),
-            AxisXY::X => write!(f, "gap-x-{}", self.size.get_class_arbitrary()),
-            AxisXY::Y => write!(f, "gap-y-{}", self.size.get_class_arbitrary()),
+            AxisXY::N => write!(f, "gap-{}", self.size),
+            AxisXY::X => write!(f, "gap-x-{}", self.size),
+            AxisXY::Y => write!(f, "gap-y-{}", self.size),
         }
     }
 }
 
 impl TailwindInstance for TailwindGap {
-    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.size.validate(ctx)
+    }
+    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let class = match self.axis {
             AxisXY::N => "gap",
             AxisXY::X => "column-gap",
             AxisXY::Y => "row-gap",
         };
         css_attributes! {
-            class => self.size.get_properties()
+            class => self.size.get_properties(ctx)
         }
     }
 }
@@ -41,13 +44,13 @@ impl TailwindGap {
     }
 }
 
-fn parse_size(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<LengthUnit> {
+fn parse_size(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<SpacingSize> {
     let size = match pattern {
-        [] => arbitrary.as_length_or_fraction()?,
-        ["px"] => LengthUnit::px(1.0),
-        [n] => {
-            let a = TailwindArbitrary::from(*n);
-            LengthUnit::rem(a.as_float()? / 4.0)
+        [] => SpacingSize::Length(arbitrary.as_length_or_fraction()?),
+        ["px"] => SpacingSize::Length(LengthUnit::px(1.0)),
+        [n] => match TailwindArbitrary::from(*n).as_float() {
+            Ok(o) => SpacingSize::Unit(o),
+            Err(_) => SpacingSize::Named(n.to_string()),
         },
         _ => return syntax_error!("Unknown gap instructions"),
     };
@@ -38,10 +38,8 @@ impl GridSize {
     pub fn parse(pattern: &str, allow_full: bool) -> Result<Self> {
         let size = match pattern {
             "auto" => Self::Auto,
-            "full" => {
-                debug_assert!(allow_full, "can't set to full for pattern");
-                Self::Full
-            },
+            "full" if allow_full => Self::Full,
+            "full" => return syntax_error!("can't set to full for pattern"),
             n => Self::Unit(TailwindArbitrary::from(n).as_integer()?),
         };
         Ok(size)
@@ -15,6 +15,9 @@ impl Display for TailwindAccentColor {
 }
 
 impl TailwindInstance for TailwindAccentColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
             "accent-color" => self.color.get_properties(ctx),
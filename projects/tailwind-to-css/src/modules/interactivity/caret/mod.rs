@@ -15,6 +15,9 @@ impl Display for TailwindCaretColor {
 }
 
 impl TailwindInstance for TailwindCaretColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
             "caret-color" => self.color.get_properties(ctx),
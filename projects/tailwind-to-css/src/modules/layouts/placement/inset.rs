@@ -15,8 +15,20 @@ impl Display for TailwindInset {
 }
 
 impl TailwindInstance for TailwindInset {
-    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
-        let size = self.kind.get_properties_rem();
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        match &self.kind {
+            UnitValue::Keyword(s) if !check_valid_auto(s) && ctx.spacing.get_step(s).is_none() => {
+                syntax_error!("Unknown spacing step: {}", s)
+            },
+            _ => Ok(()),
+        }
+    }
+    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
+        let size = match &self.kind {
+            UnitValue::Number { n, .. } => ctx.spacing.get_number(*n),
+            UnitValue::Keyword(s) => ctx.spacing.get_step(s).unwrap_or_else(|| s.to_string()),
+            kind => kind.get_properties_rem(),
+        };
         match self.axis {
             AxisXY::X => css_attributes! {
                 "right" => &size,
@@ -39,7 +51,11 @@ impl TailwindInstance for TailwindInset {
 impl TailwindInset {
     pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary, negative: Negative) -> Result<Self> {
         let (axis, rest) = AxisXY::split_xyn(pattern);
-        let kind = get_kind_px_full_auto_fract("inset", rest, arbitrary, negative)?;
+        let kind = get_kind_px_full_auto_fract("inset", rest, arbitrary, negative).or_else(|e| match rest {
+            // steps of the spacing scale, `inset-gutter`
+            [name] => Ok(UnitValue::Keyword(name.to_string())),
+            _ => Err(e),
+        })?;
         Ok(Self { axis, kind })
     }
     /// <https://developer.mozilla.org/en-US/docs/Web/CSS/inset#syntax>
@@ -18,9 +18,12 @@ impl Display for TailwindScrollMargin {
 
 // noinspection DuplicatedCode
 impl TailwindInstance for TailwindScrollMargin {
-    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.size.validate(ctx)
+    }
+    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let mut out = CssAttributes::default();
-        self.axis.write_attributes(&mut out, self.size.get_properties());
+        self.axis.write_attributes(&mut out, self.size.get_properties(ctx));
         out
     }
 }
@@ -4,10 +4,11 @@ use std::{
 };
 
 use crate::{
-    css_attributes, syntax_error, CssAttributes, Result, SpacingAxis, TailwindArbitrary, TailwindBuilder, TailwindInstance,
+    css_attributes, syntax_error, CssAttributes, LengthUnit, Result, SpacingAxis, TailwindArbitrary, TailwindBuilder,
+    TailwindInstance,
 };
 
-use self::size::SpacingSize;
+pub(crate) use self::size::SpacingSize;
 pub use self::{
     margin::TailwindMargin, margin_scroll::TailwindScrollMargin, padding::TailwindPadding,
     padding_scroll::TailwindScrollPadding, space::TailwindSpace, space_reverse::TailwindSpaceReverse,
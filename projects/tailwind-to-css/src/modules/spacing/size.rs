@@ -1,9 +1,12 @@
 use super::*;
 
 #[derive(Debug, Clone)]
-pub(super) enum SpacingSize {
+pub(crate) enum SpacingSize {
     Unit(f32),
     Standard(String),
+    /// A step of the spacing scale, `p-gutter`
+    Named(String),
+    Length(LengthUnit),
     Arbitrary(TailwindArbitrary),
 }
 
@@ -12,6 +15,8 @@ impl Display for SpacingSize {
         match self {
             Self::Unit(s) => write!(f, "{}", s),
             Self::Standard(s) => write!(f, "{}", s),
+            Self::Named(s) => write!(f, "{}", s),
+            Self::Length(s) => write!(f, "{}", s.get_class_arbitrary()),
             Self::Arbitrary(s) => s.write(f),
         }
     }
@@ -23,7 +28,10 @@ impl SpacingSize {
             [] => Self::parse_arbitrary(arbitrary),
             ["px"] => Ok(Self::Arbitrary(TailwindArbitrary::from("1px"))),
             [n] if check_valid(n) => Ok(Self::Standard(n.to_string())),
-            [n] => Ok(Self::Unit(TailwindArbitrary::from(*n).as_float()?)),
+            [n] => match TailwindArbitrary::from(*n).as_float() {
+                Ok(o) => Ok(Self::Unit(o)),
+                Err(_) => Ok(Self::Named(n.to_string())),
+            },
             _ => syntax_error!("Unknown padding instructions: {}", pattern.join("-")),
         }
     }
@@ -33,10 +41,19 @@ impl SpacingSize {
     }
 }
 impl SpacingSize {
-    pub fn get_properties(&self) -> String {
+    /// Named steps only exist in the spacing scale of the theme
+    pub fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
         match self {
-            Self::Unit(x) => format!("{}rem", x / 4.0),
+            Self::Named(x) if ctx.spacing.get_step(x).is_none() => syntax_error!("Unknown spacing step: {}", x),
+            _ => Ok(()),
+        }
+    }
+    pub fn get_properties(&self, ctx: &TailwindBuilder) -> String {
+        match self {
+            Self::Unit(x) => ctx.spacing.get_number(*x),
             Self::Standard(x) => x.to_string(),
+            Self::Named(x) => ctx.spacing.get_step(x).unwrap_or_default(),
+            Self::Length(x) => x.get_properties(),
             Self::Arbitrary(x) => x.get_properties(),
         }
     }
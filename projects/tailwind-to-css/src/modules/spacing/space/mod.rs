@@ -19,13 +19,16 @@ impl Display for TailwindSpace {
 }
 
 impl TailwindInstance for TailwindSpace {
-    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.size.validate(ctx)
+    }
+    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let class = match self.axis {
             true => "margin-left",
             false => "margin-top",
         };
         css_attributes! {
-            class => self.size.get_properties()
+            class => self.size.get_properties(ctx)
         }
     }
 }
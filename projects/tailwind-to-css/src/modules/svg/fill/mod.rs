@@ -15,6 +15,9 @@ impl Display for TailwindFillColor {
 }
 
 impl TailwindInstance for TailwindFillColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let color = self.color.get_properties(ctx);
         css_attributes! {
@@ -15,6 +15,9 @@ impl Display for TailwindStrokeColor {
 }
 
 impl TailwindInstance for TailwindStrokeColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let color = self.color.get_properties(ctx);
         css_attributes! {
@@ -80,7 +80,9 @@ impl WordBreak {
             ["all"] => Self::Standard("break-all".to_string()),
             _ => {
                 let kind = pattern.join("-");
-                debug_assert!(Self::check_valid(&kind));
+                if !Self::check_valid(&kind) {
+                    return syntax_error!("Unknown break instructions: {}", kind);
+                }
                 Self::Standard(kind)
             },
         };
@@ -15,6 +15,9 @@ impl Display for TailwindDecorationColor {
 }
 
 impl TailwindInstance for TailwindDecorationColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        self.color.validate(ctx)
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
             "text-decoration-color" => self.color.get_properties(ctx)
@@ -7,10 +7,39 @@ pub struct TailwindFontFamily {
     kind: StandardValue,
 }
 
-crate::macros::sealed::keyword_instance!(TailwindFontFamily => "font-family");
+impl<T> From<T> for TailwindFontFamily
+where
+    T: Into<String>,
+{
+    fn from(kind: T) -> Self {
+        Self { kind: StandardValue::from(kind.into()) }
+    }
+}
 
 impl Display for TailwindFontFamily {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "font-[{}]", self.kind)
+        match &self.kind {
+            StandardValue::Keyword(s) => write!(f, "font-{}", s),
+            StandardValue::Arbitrary(s) => write!(f, "font-[{}]", s),
+        }
+    }
+}
+
+impl TailwindInstance for TailwindFontFamily {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        match &self.kind {
+            StandardValue::Keyword(s) if ctx.fonts.get_family(s).is_empty() => syntax_error!("Unknown font family: {}", s),
+            _ => Ok(()),
+        }
+    }
+    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
+        let family = match &self.kind {
+            // registered families, e.g. `font-sans`
+            StandardValue::Keyword(s) => ctx.fonts.get_family(s),
+            StandardValue::Arbitrary(s) => s.get_properties(),
+        };
+        css_attributes! {
+            "font-family" => family
+        }
     }
 }
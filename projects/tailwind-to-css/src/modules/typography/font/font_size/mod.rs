@@ -4,6 +4,8 @@ use super::*;
 #[derive(Debug, Clone)]
 pub struct TailwindFontSize {
     kind: UnitValue,
+    /// The line height after the slash, `7` in `text-lg/7`
+    leading: Option<TailwindLeading>,
 }
 
 
@@ -14,6 +16,7 @@ where
     fn from(kind: T) -> Self {
         Self {
             kind: kind.into(),
+            leading: None,
         }
     }
 }
@@ -26,25 +29,28 @@ impl Display for TailwindFontSize {
             UnitValue::Length(s) => write!(f, "text-{}", s),  // ex: text-[2.3rem]
             UnitValue::Keyword(s) => {write!(f, "text-{}", s)}  // ex: text-sm
             UnitValue::Arbitrary(s) => write!(f, "text-{}", s),
+        }?;
+        match &self.leading {
+            Some(leading) => write!(f, "/{}", leading.get_class()),
+            None => Ok(()),
         }
     }
 }
 
 impl TailwindInstance for TailwindFontSize {
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
-        match &self.kind {
-            UnitValue::Keyword(s) => {
-                // Get font-size and line-height properties for built-in Keywords
-                return ctx.fonts.get_size(s).get_properties();
-                
-            }
-            _ => {
-                css_attributes! {
-                    "font-size" => self.kind.get_properties_rem(),
-                }
-            }
-
+        let mut css = match &self.kind {
+            // Get font-size and line-height properties for built-in Keywords
+            UnitValue::Keyword(s) => ctx.fonts.get_size(s).get_properties(),
+            _ => css_attributes! {
+                "font-size" => self.kind.get_properties_rem(),
+            },
+        };
+        // `text-lg/7` overrides the line height of the named size
+        if let Some(leading) = &self.leading {
+            css.insert("line-height", leading.get_properties());
         }
+        css
     }
 }
 
@@ -52,10 +58,30 @@ impl TailwindFontSize {
     // https://tailwindcss.com/docs/font-size
     pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
         let kind = UnitValue::positive_parser("text-size", Self::check_valid_keyword, true, false, false)(pattern, arbitrary)?;
-        Ok(Self { kind })
+        let leading = arbitrary.as_modifier().map(TailwindLeading::parse_modifier).transpose()?;
+        Ok(Self { kind, leading })
     }
 
     pub fn check_valid_keyword(mode: &str) -> bool {
         ["xs", "sm", "base", "md", "lg", "xl", "2xl", "3xl", "4xl", "5xl", "6xl", "7xl", "8xl", "9xl"].contains(&mode)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::TailwindBuilder;
+
+    #[test]
+    fn test_leading_modifier() {
+        let mut builder = TailwindBuilder::default();
+        builder.preflight.disable = true;
+        let mut inline = |class: &str| builder.inline(class).unwrap().1;
+        assert_eq!(inline("text-lg"), "font-size:1.125rem;line-height:1.75rem;");
+        assert_eq!(inline("text-lg/7"), "font-size:1.125rem;line-height:1.75rem;");
+        assert_eq!(inline("text-sm/[1.5]"), "font-size:0.875rem;line-height:1.5;");
+        assert_eq!(inline("text-[2rem]/tight"), "font-size:2rem;line-height:1.25;");
+        assert_eq!(inline("w-1/2"), "width:50%;");
+        // see `TailwindInstruction::takes_modifier`
+        assert!(builder.inline("grid-cols-[1fr_2fr]/2").is_err());
+    }
+}
@@ -33,10 +33,10 @@ pub fn font_adaptor(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<B
             TailwindFontFamily::from(rest.join("-")).boxed()
         },
         // Try parse as font weight if pattern has one segment
-        // - will fail if segment is not an int
+        // - fallback to the named font family if segment is not an int, checked against the theme
         [n] => {
             let a = TailwindArbitrary::from(*n);
-            maybe_weight(&a)?
+            maybe_weight(&a).unwrap_or_else(|_| TailwindFontFamily::from(*n).boxed())
         },
         // Try parse as font family if pattern has 1+ segments
         [_, _rest @ ..] => {
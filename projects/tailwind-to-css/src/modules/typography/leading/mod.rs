@@ -25,26 +25,8 @@ impl Display for TailwindLeading {
 
 impl TailwindInstance for TailwindLeading {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
-        // Map the keywords to their specific values.
-        let line_height = match &self.kind {
-            UnitValue::Keyword(k) => match k.as_str() {
-                // Tailwind v3 keywords that map to unitless values
-                "none" => "1.0",
-                "tight" => "1.25",
-                "snug" => "1.375",
-                "normal" => "1.5",
-                "relaxed" => "1.625",
-                "loose" => "2.0",
-                // Standard CSS keyword
-                "default" => "normal",
-                _ => "1.5", // Default
-            }.to_string(),
-            // For UnitValue, use get_properties_rem to convert numbers to rem.
-            _ => self.kind.get_properties_rem(),
-        };
-
         css_attributes! {
-            "line-height" => line_height
+            "line-height" => self.get_properties()
         }
     }
 }
@@ -63,6 +45,38 @@ impl TailwindLeading {
         })
     }
 
+    /// The line height after a font size, `7` in `text-lg/7` or `[1.5]` in `text-lg/[1.5]`
+    pub fn parse_modifier(modifier: &str) -> Result<Self> {
+        match modifier.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(s) => Self::parse(&[], &TailwindArbitrary::from(s)),
+            None => Self::parse(&[modifier], &TailwindArbitrary::from("")),
+        }
+    }
+    /// `7` in `leading-7`
+    pub fn get_class(&self) -> String {
+        self.kind.to_string()
+    }
+    /// The value of `line-height`
+    pub fn get_properties(&self) -> String {
+        // Map the keywords to their specific values.
+        match &self.kind {
+            UnitValue::Keyword(k) => match k.as_str() {
+                // Tailwind v3 keywords that map to unitless values
+                "none" => "1.0",
+                "tight" => "1.25",
+                "snug" => "1.375",
+                "normal" => "1.5",
+                "relaxed" => "1.625",
+                "loose" => "2.0",
+                // Standard CSS keyword
+                "default" => "normal",
+                _ => "1.5", // Default
+            }
+            .to_string(),
+            // For UnitValue, use get_properties_rem to convert numbers to rem.
+            _ => self.kind.get_properties_rem(),
+        }
+    }
     /// Checks for valid line-height keywords
     pub fn check_valid(mode: &str) -> bool {
         // "default" is from https://developer.mozilla.org/en-US/docs/Web/CSS/line-height#normal
@@ -12,6 +12,7 @@ pub use self::{
     },
     indent::TailwindIndent,
     leading::TailwindLeading,
+    prose::TailwindProse,
     list::{list_position::TailwindListPosition, list_type::TailwindListStyle},
     text::{
         text_adaptor, text_align::TailwindTextAlignment, text_color::TailwindTextColor, text_overflow::TailwindTextOverflow,
@@ -23,7 +24,7 @@ pub use self::{
 };
 use crate::{
     css_attributes, TailwindError, syntax_error, CssAttributes, LengthUnit, Result, StandardValue, TailwindArbitrary, TailwindBreakAfter,
-    TailwindBreakBefore, TailwindBreakInside, TailwindBuilder, TailwindColor, TailwindInstance, UnitValue
+    TailwindBreakBefore, TailwindBreakInside, TailwindBuilder, TailwindColor, TailwindInstance, TypographySystem, UnitValue
 };
 use std::{
     collections::BTreeSet,
@@ -37,6 +38,7 @@ mod font;
 mod indent;
 mod leading;
 mod list;
+mod prose;
 mod text;
 mod tracking;
 mod underline_offset;
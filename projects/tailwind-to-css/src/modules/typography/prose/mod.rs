@@ -0,0 +1,119 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+#[derive(Debug, Clone)]
+pub struct TailwindProse {
+    kind: ProseKind,
+}
+
+#[derive(Debug, Clone)]
+enum ProseKind {
+    /// `prose`
+    Default,
+    /// `prose-invert`
+    Invert,
+    /// `prose-lg` or `prose-slate`, sizes are looked up before palettes
+    Named(String),
+}
+
+/// The `--tw-prose-*` colors, as palette weights of the light theme and values of the inverted theme
+const PROSE_COLORS: &[(&str, u32, &str)] = &[
+    ("body", 700, "300"),
+    ("headings", 900, "white"),
+    ("lead", 600, "400"),
+    ("links", 900, "white"),
+    ("bold", 900, "white"),
+    ("counters", 500, "400"),
+    ("bullets", 300, "600"),
+    ("hr", 200, "700"),
+    ("quotes", 900, "100"),
+    ("quote-borders", 200, "700"),
+    ("captions", 500, "400"),
+    ("code", 900, "white"),
+    ("pre-code", 200, "300"),
+    ("pre-bg", 800, "rgb(0 0 0 / 50%)"),
+    ("th-borders", 300, "600"),
+    ("td-borders", 200, "700"),
+];
+
+impl Display for TailwindProse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ProseKind::Default => write!(f, "prose"),
+            ProseKind::Invert => write!(f, "prose-invert"),
+            ProseKind::Named(s) => write!(f, "prose-{}", s),
+        }
+    }
+}
+
+impl TailwindInstance for TailwindProse {
+    fn inlineable(&self) -> bool {
+        false
+    }
+    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
+        match &self.kind {
+            ProseKind::Default => {
+                let mut out = Self::get_colors(ctx, "gray");
+                out += Self::get_rules(ctx, "DEFAULT");
+                out
+            },
+            ProseKind::Invert => {
+                let mut out = CssAttributes::default();
+                for (name, ..) in PROSE_COLORS {
+                    out.insert(format!("--tw-prose-{}", name), format!("var(--tw-prose-invert-{})", name));
+                }
+                out
+            },
+            ProseKind::Named(name) if ctx.typography.get_style(name).is_some() => Self::get_rules(ctx, name),
+            ProseKind::Named(name) => Self::get_colors(ctx, name),
+        }
+    }
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        match &self.kind {
+            ProseKind::Named(name) if ctx.typography.get_style(name).is_none() && ctx.palettes.try_get_color(name, 500).is_err() => {
+                syntax_error!("Unknown prose style: {}", name)
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+impl TailwindProse {
+    /// <https://github.com/tailwindlabs/tailwindcss-typography#basic-usage>
+    pub fn parse(pattern: &[&str], _arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = match pattern {
+            [] => ProseKind::Default,
+            ["invert"] => ProseKind::Invert,
+            [name] => ProseKind::Named(name.to_string()),
+            _ => return syntax_error!("Unknown prose instructions: {}", pattern.join("-")),
+        };
+        Ok(Self { kind })
+    }
+    /// The element rules of a style as nested rules
+    fn get_rules(ctx: &TailwindBuilder, style: &str) -> CssAttributes {
+        let mut out = CssAttributes::default();
+        for (element, attributes) in ctx.typography.get_style(style).into_iter().flatten() {
+            match element.as_str() {
+                "" => out += attributes.clone(),
+                _ => out.insert_nested(format!("& {}", TypographySystem::get_selector(element)), attributes.clone()),
+            }
+        }
+        out
+    }
+    /// The `--tw-prose-*` colors of the light and the inverted theme, picked from a palette
+    fn get_colors(ctx: &TailwindBuilder, palette: &str) -> CssAttributes {
+        let color = |weight: u32| TailwindColor::Themed { name: palette.to_string(), weight, alpha: None }.get_properties(ctx);
+        let mut out = CssAttributes::default();
+        for (name, light, _) in PROSE_COLORS {
+            out.insert(format!("--tw-prose-{}", name), color(*light));
+        }
+        for (name, _, invert) in PROSE_COLORS {
+            let value = match invert.parse::<u32>() {
+                Ok(weight) => color(weight),
+                Err(_) => invert.to_string(),
+            };
+            out.insert(format!("--tw-prose-invert-{}", name), value);
+        }
+        out
+    }
+}
@@ -18,6 +18,8 @@ pub fn text_adaptor(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<B
         // https://tailwindcss.com/docs/font-size  Built-in
         [_s @ ("xs" | "sm" | "base" | "md" | "lg" | "xl" | "2xl" | "3xl" | "4xl" | "5xl" | "6xl" | "7xl" | "8xl" | "9xl")] =>
             TailwindFontSize::parse(pattern, arbitrary)?.boxed(),
+        // https://tailwindcss.com/docs/text-color  Arbitrary, e.g. `text-[oklch(...)]`, `text-[var(--brand)]/50`
+        [] if arbitrary.is_color() => TailwindTextColor::parse_arbitrary(arbitrary)?.boxed(),
         // https://tailwindcss.com/docs/font-size  Arbitrary 
         [] => TailwindFontSize::parse(pattern, arbitrary)?.boxed(),
         // https://tailwindcss.com/docs/text-color
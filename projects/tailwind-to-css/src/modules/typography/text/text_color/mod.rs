@@ -15,7 +15,19 @@ impl Display for TailwindTextColor {
 }
 
 impl TailwindInstance for TailwindTextColor {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        match &self.color {
+            TailwindColor::Keyword { name, alpha: None } if ctx.fonts.try_get_size(name).is_some() => Ok(()),
+            color => color.validate(ctx),
+        }
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
+        // `text-huge` can only be told apart from a color once the theme is known
+        if let TailwindColor::Keyword { name, alpha: None } = &self.color {
+            if let (Err(_), Some(size)) = (ctx.palettes.try_get_keyword_color(name), ctx.fonts.try_get_size(name)) {
+                return size.get_properties();
+            }
+        }
         css_attributes! {
             "color" => self.color.get_properties(ctx)
         }
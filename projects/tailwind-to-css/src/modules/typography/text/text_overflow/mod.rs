@@ -50,7 +50,9 @@ impl TailwindTextOverflow {
             [] => TextOverflow::Arbitrary(arbitrary.to_owned()),
             _ => {
                 let input = pattern.join("-");
-                debug_assert!(Self::check_valid(&input));
+                if !Self::check_valid(&input) {
+                    return syntax_error!("Unknown text-overflow instructions: {}", input);
+                }
                 TextOverflow::Standard(input)
             },
         };
@@ -13,11 +13,19 @@ impl Display for TailwindTracking {
 }
 
 impl TailwindInstance for TailwindTracking {
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        match &self.kind {
+            StandardValue::Keyword(s) if !Self::check_valid(s) && ctx.fonts.get_tracking(s).is_none() => {
+                syntax_error!("Unknown letter spacing: {}", s)
+            },
+            _ => Ok(()),
+        }
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let tracking = match &self.kind {
             StandardValue::Keyword(s) => match s.as_str() {
                 s if Self::check_valid(s) => s.to_string(),
-                _ => format!("{}em", ctx.fonts.get_tracking(s)),
+                _ => format!("{}em", ctx.fonts.get_tracking(s).unwrap_or_default()),
             },
             StandardValue::Arbitrary(s) => s.get_properties(),
         };
@@ -1,3 +1,4 @@
+use crate::{syntax_error, Result};
 use std::collections::BTreeMap;
 
 mod traits;
@@ -20,17 +21,32 @@ impl BreakPointSystem {
         new
     }
 
-    pub fn try_get_width(&self, name: &str) -> Result<usize, String> {
+    pub fn try_get_width(&self, name: &str) -> std::result::Result<usize, String> {
         match self.inner.get(name) {
             Some(bp) => Ok(bp.width),
             None => Err(format!("no such breakpoint: {}", name)),
         }
     }
 
+    /// The registered breakpoints from narrow to wide, `("md", 768)`
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+        let mut sorted: Vec<_> = self.inner.iter().map(|(name, bp)| (name.as_str(), bp.width)).collect();
+        sorted.sort_by_key(|(_, width)| *width);
+        sorted.into_iter()
+    }
+
     #[inline]
     pub fn register(&mut self, name: String, width: usize) -> Option<BreakPoint> {
         self.inner.insert(name, BreakPoint { width })
     }
+
+    /// Breakpoints must be whole pixels, so that they can be ordered against each other.
+    pub fn parse_width(width: &str) -> Result<usize> {
+        match width.strip_suffix("px").map(|s| s.parse::<usize>()) {
+            Some(Ok(o)) => Ok(o),
+            _ => syntax_error!("Breakpoint must be an integer in px, found: {}", width),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -39,3 +55,37 @@ pub struct BreakPoint {
     /// unit: px
     width: usize,
 }
+
+/// The viewport range of a responsive rule, `md:max-xl:` => `[768px, 1280px)`
+///
+/// Ranges are ordered so that the cascade works, max-only ranges come first from wide to narrow,
+/// then min ranges from narrow to wide.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ScreenRange {
+    /// Inclusive lower bound
+    /// unit: px
+    pub min: Option<usize>,
+    /// Exclusive upper bound
+    /// unit: px
+    pub max: Option<usize>,
+}
+
+impl ScreenRange {
+    /// `md:`, `min-[900px]:`
+    #[inline]
+    pub fn min(width: usize) -> Self {
+        Self { min: Some(width), max: None }
+    }
+    /// `max-md:`, `max-[900px]:`
+    #[inline]
+    pub fn max(width: usize) -> Self {
+        Self { min: None, max: Some(width) }
+    }
+    /// Narrow the range so that both conditions hold
+    pub fn intersect(self, other: Self) -> Self {
+        Self {
+            min: self.min.into_iter().chain(other.min).max(),
+            max: self.max.into_iter().chain(other.max).min(),
+        }
+    }
+}
@@ -1,9 +1,36 @@
 use super::*;
-use std::fmt::{Display, Formatter};
+use std::{
+    cmp::{Ordering, Reverse},
+    fmt::{Display, Formatter},
+};
 
 impl Display for BreakPoint {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "@media (min-width: {}px) {{", self.width)?;
-        f.write_str("}")
+        write!(f, "@media (min-width: {}px)", self.width)
+    }
+}
+
+impl Display for ScreenRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("@media ")?;
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => write!(f, "(min-width: {}px) and (max-width: {}.98px)", min, max.saturating_sub(1)),
+            (Some(min), None) => write!(f, "(min-width: {}px)", min),
+            (None, Some(max)) => write!(f, "(max-width: {}.98px)", max.saturating_sub(1)),
+            (None, None) => f.write_str("all"),
+        }
+    }
+}
+
+impl PartialOrd for ScreenRange {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScreenRange {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |s: &Self| (s.min, Reverse(s.max.is_none()), Reverse(s.max));
+        key(self).cmp(&key(other))
     }
 }
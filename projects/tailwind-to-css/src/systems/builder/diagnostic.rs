@@ -0,0 +1,152 @@
+use super::*;
+use std::{
+    fmt::{Display, Formatter},
+    ops::Range,
+};
+
+/// A class rejected by [`TailwindBuilder::trace`] or one of the inline modes
+#[derive(Debug)]
+pub struct TailwindDiagnostic {
+    /// The rejected class, e.g. `hover:bgg-red-500`
+    pub class: String,
+    /// Why the class was rejected, the range is the byte offset of the class in the traced string
+    pub error: TailwindError,
+    /// A known utility close to the class, e.g. `hover:bg-red-500`
+    pub suggestion: Option<String>,
+}
+
+impl Display for TailwindDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}`", self.class)?;
+        if let Some(range) = &self.error.range {
+            write!(f, " at {}..{}", range.start, range.end)?;
+        }
+        write!(f, ": {}", self.error.kind)?;
+        if let Some(s) = &self.suggestion {
+            write!(f, ", did you mean `{}`?", s)?;
+        }
+        Ok(())
+    }
+}
+
+impl TailwindBuilder {
+    /// All classes rejected since the last [`TailwindBuilder::clear`], in order of appearance
+    #[inline]
+    pub fn diagnostics(&self) -> &[TailwindDiagnostic] {
+        &self.diagnostics
+    }
+    /// Take the rejected classes out of the builder, e.g. to report them once per file
+    #[inline]
+    pub fn take_diagnostics(&mut self) -> Vec<TailwindDiagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+    /// Record every rejected class of the style, and point the error at the first one
+    pub(crate) fn diagnose(&mut self, style: &str, mut error: TailwindError) -> TailwindError {
+        let start = self.diagnostics.len();
+        for range in class_ranges(style) {
+            let class = &style[range.clone()];
+            let (styles, errors) = self.parser.parse_tolerant(class);
+            for e in errors {
+                let class = class[e.range.clone()].to_string();
+                self.diagnostics.push(TailwindDiagnostic { class, error: syntax_error(e, range.start), suggestion: None });
+            }
+            for item in styles.into_iter().map(TailwindInstruction::from) {
+                if let Err(mut e) = self.check_instruction(&item) {
+                    e.set_range(range.start, range.end);
+                    let suggestion = item.suggest();
+                    // a stray `(` parses to an empty instruction
+                    let class = match item.to_string() {
+                        s if s.is_empty() => class.to_string(),
+                        s => s,
+                    };
+                    self.diagnostics.push(TailwindDiagnostic { class, error: e, suggestion });
+                }
+            }
+        }
+        if let Some(range) = self.diagnostics.get(start).and_then(|d| d.error.range.clone()) {
+            error.set_range(range.start, range.end);
+        }
+        error
+    }
+    pub(crate) fn check_instruction(&self, item: &TailwindInstruction) -> Result<()> {
+        get_instance(self, item)?;
+        for variant in item.view_variants() {
+            variant.get_variant(self)?;
+        }
+        Ok(())
+    }
+}
+
+/// The byte ranges of the classes in a class attribute, a group such as `hover:(a b)` is one class
+fn class_ranges(style: &str) -> Vec<Range<usize>> {
+    let mut out = vec![];
+    let mut depth = 0usize;
+    let mut start = None;
+    for (i, c) in style.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    out.push(s..i);
+                }
+                continue;
+            },
+            _ => {},
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(s) = start {
+        out.push(s..style.len());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TailwindBuilder;
+
+    #[test]
+    fn test_diagnostics() {
+        let mut tw = TailwindBuilder::default();
+        let style = "p-4 hover:bgg-red-500 txet-lg bg-red-500 hoverr:underline (";
+        let error = tw.trace(style, false).unwrap_err();
+        assert_eq!(error.range, Some(4..21));
+        let found: Vec<_> = tw.diagnostics().iter().map(|d| (d.class.as_str(), d.error.range.clone(), d.suggestion.as_deref())).collect();
+        assert_eq!(found, [
+            ("hover:bgg-red-500", Some(4..21), Some("hover:bg-red-500")),
+            ("txet-lg", Some(22..29), Some("text-lg")),
+            ("hoverr:underline", Some(41..57), None),
+            ("(", Some(58..59), None),
+        ]);
+        assert!(tw.diagnostics()[1].to_string().ends_with(", did you mean `text-lg`?"));
+        assert_eq!(tw.take_diagnostics().len(), 4);
+        assert!(tw.inline("bg-red-500").is_ok());
+        assert!(tw.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn test_keep_valid_classes() {
+        let mut tw = TailwindBuilder::default();
+        tw.preflight.disable = true;
+        assert!(tw.trace("p-4 bgg-red-500 m-2", false).is_err());
+        let found: Vec<_> = tw.diagnostics().iter().map(|d| d.class.as_str()).collect();
+        assert_eq!(found, ["bgg-red-500"]);
+        let css = tw.bundle().unwrap();
+        assert!(css.contains(".p-4"));
+        assert!(css.contains(".m-2"));
+    }
+
+    #[test]
+    fn test_unclosed_bracket() {
+        let mut tw = TailwindBuilder::default();
+        tw.preflight.disable = true;
+        let error = tw.trace("p-4 w-[10px m-2", false).unwrap_err();
+        assert_eq!(error.range, Some(4..11));
+        let found: Vec<_> = tw.diagnostics().iter().map(|d| (d.class.as_str(), d.error.kind.to_string())).collect();
+        assert_eq!(found, [("w-[10px", "SyntaxError: missing `]`".to_string())]);
+        let css = tw.bundle().unwrap();
+        assert!(css.contains(".m-2"));
+        assert!(!css.contains("width"));
+    }
+}
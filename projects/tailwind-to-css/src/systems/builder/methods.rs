@@ -6,5 +6,41 @@ impl TailwindBuilder {
     pub fn clear(&mut self) {
         self.objects.clear();
         self.bundles.clear();
+        self.variables.clear();
+        self.diagnostics.clear();
+        self.sources.clear();
+    }
+    /// Remember the `--color-*` variables referenced by the rule, `bg-[var(--color-nope)]` is not a palette color and
+    /// is left to the user to declare
+    pub(crate) fn record_variables(&mut self, css: &CssInstance) {
+        if !self.theme_variables {
+            return;
+        }
+        let text = format!("{}{}", css.attribute, css.addition);
+        for (start, _) in text.match_indices("var(--color-") {
+            let name = &text[start + "var(--color-".len()..];
+            let name = match name.find(')') {
+                Some(end) => &name[..end],
+                None => continue,
+            };
+            if self.palettes.try_get_variable(name).is_ok() {
+                self.insert_variable(name.to_string());
+            }
+        }
+    }
+    /// Declare the used theme variables, `:root{--color-red-500:rgba(251, 44, 54, 1);}`
+    pub(crate) fn write_variables(&self, f: &mut dyn Write) -> Result<()> {
+        if self.variables.is_empty() {
+            return Ok(());
+        }
+        f.write_str(":root{")?;
+        // the palettes may have changed since the rule was traced
+        for name in &self.variables {
+            if let Ok(color) = self.palettes.try_get_variable(name) {
+                write!(f, "--color-{}:{};", name, self.color_format.write_color(&color))?;
+            }
+        }
+        f.write_char('}')?;
+        Ok(())
     }
 }
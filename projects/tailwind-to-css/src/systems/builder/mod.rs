@@ -1,12 +1,21 @@
-use std::{collections::BTreeSet, fmt::Debug};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Debug, Write},
+};
 
 use crate::{systems::instruction::TailwindInstruction, *};
+use tailwind_ast::{AstError, TailwindParser};
 
-pub use self::base62::{Base62, BASE62};
+pub use self::{
+    base62::{Base62, BASE62},
+    diagnostic::TailwindDiagnostic,
+};
 
 mod base62;
+mod diagnostic;
 mod methods;
 mod setter;
+mod source;
 
 ///
 #[derive(Debug)]
@@ -29,8 +38,34 @@ pub struct TailwindBuilder {
     ///
     /// Only determined when packing
     pub effects: EffectSystem,
+    /// All dynamically registered spacing steps
+    ///
+    /// Only determined when packing
+    pub spacing: SpacingSystem,
+    /// All dynamically registered `prose` element rules
+    ///
+    /// Only determined when packing
+    pub typography: TypographySystem,
+    /// How the `dark:` variant is compiled
+    pub dark_mode: DarkMode,
+    /// Output theme colors as `var(--color-red-500)`, and declare the used ones in a `:root` layer
+    ///
+    /// So that the theme can be overridden at runtime.
+    pub theme_variables: bool,
+    /// How resolved colors are written, `rgba()` by default
+    pub color_format: ColorFormat,
     pub(crate) objects: BTreeSet<CssInstance>,
     pub(crate) bundles: BTreeSet<CssBundle>,
+    /// Names of the `--color-*` variables referenced by the rules, e.g. `red-500`
+    pub(crate) variables: BTreeSet<String>,
+    /// Classes rejected by the trace and inline modes
+    pub(crate) diagnostics: Vec<TailwindDiagnostic>,
+    /// Parsed class strings, by hash
+    pub(crate) parser: TailwindParser,
+    /// The rules of each source, the ones traced outside of a source are not recorded
+    pub(crate) sources: BTreeMap<String, source::SourceRecord>,
+    /// The source the rules are recorded for, see [`TailwindBuilder::begin_source`]
+    pub(crate) current_source: Option<String>,
 }
 
 impl TailwindBuilder {
@@ -155,8 +190,23 @@ impl TailwindBuilder {
         if !self.preflight.disable {
             out.push_str(&self.preflight.to_string());
         }
+        self.write_variables(&mut out)?;
+        let mut screens: BTreeMap<Option<ScreenRange>, Vec<&CssInstance>> = BTreeMap::new();
         for item in &self.objects {
-            item.write_css(&mut out)?;
+            screens.entry(item.get_screen()).or_default().push(item);
+        }
+        for (screen, mut items) in screens {
+            if let Some(s) = screen {
+                write!(out, "{}{{", s)?;
+            }
+            // rules with variants come last, so they can override the plain ones
+            items.sort_by_key(|i| i.get_order());
+            for item in items {
+                item.write_css(&mut out)?;
+            }
+            if screen.is_some() {
+                out.push('}');
+            }
         }
         for item in &self.bundles {
             item.write_css(&mut out)?;
@@ -165,36 +215,77 @@ impl TailwindBuilder {
     }
 }
 
-fn parse_tailwind(input: &str) -> Result<Vec<TailwindInstruction>> {
-    let styles = tailwind_ast::parse_tailwind(input)?;
-    Ok(styles.into_iter().map(TailwindInstruction::from).collect())
+/// Repeated class strings are taken from the parser cache, a class that can not be parsed is skipped
+fn parse_tailwind(tw: &mut TailwindBuilder, input: &str) -> (Vec<TailwindInstruction>, Option<TailwindError>) {
+    let (styles, errors) = tw.parser.parse_tolerant(input);
+    let error = errors.into_iter().next().map(|e| syntax_error(e, 0));
+    (styles.into_iter().map(TailwindInstruction::from).collect(), error)
+}
+
+/// The error of a class that can not be parsed, the class starts `offset` bytes into the traced string
+fn syntax_error(e: AstError, offset: usize) -> TailwindError {
+    let mut error = TailwindError::syntax_error(e.message);
+    error.set_range(offset + e.range.start, offset + e.range.end);
+    error
+}
+
+/// The instance of the instruction, checked against the theme of the builder
+fn get_instance(tw: &TailwindBuilder, item: &TailwindInstruction) -> Result<Box<dyn TailwindInstance>> {
+    let instance = item.get_instance()?;
+    instance.validate(tw)?;
+    Ok(instance)
 }
 
 fn try_trace(tw: &mut TailwindBuilder, style: &str, obfuscate: bool) -> Result<CssBundle> {
-    let parsed = parse_tailwind(style)?;
+    trace_styles(tw, style, obfuscate).map_err(|e| tw.diagnose(style, e))
+}
+
+/// Every valid class is inserted, even if another class of the style is rejected
+fn trace_styles(tw: &mut TailwindBuilder, style: &str, obfuscate: bool) -> Result<CssBundle> {
+    let (parsed, mut error) = parse_tailwind(tw, style);
     let mut out = CssBundle::default();
     for item in parsed {
-        let i = CssInstance::new(&*item.get_instance()?, tw, obfuscate);
+        let i = match get_instance(tw, &item).and_then(|i| CssInstance::new(&*i, tw, obfuscate).with_variants(&item, tw)) {
+            Ok(o) => o.with_important(&item),
+            Err(e) => {
+                error.get_or_insert(e);
+                continue;
+            },
+        };
+        tw.record_variables(&i);
         out.add_trace(&i);
-        tw.objects.insert(i);
+        tw.insert_object(i);
+    }
+    match error {
+        Some(e) => Err(e),
+        None => Ok(out),
     }
-    Ok(out)
 }
 
 fn try_inline(tw: &mut TailwindBuilder, style: &str, mode: CssInlineMode) -> Result<CssBundle> {
-    let parsed = parse_tailwind(style)?;
+    inline_styles(tw, style, mode).map_err(|e| tw.diagnose(style, e))
+}
+
+fn inline_styles(tw: &mut TailwindBuilder, style: &str, mode: CssInlineMode) -> Result<CssBundle> {
+    let (parsed, error) = parse_tailwind(tw, style);
+    if let Some(e) = error {
+        return Err(e);
+    }
     let mut out = CssBundle::default();
     for item in parsed {
-        let i = CssInstance::new(&*item.get_instance()?, tw, true);
+        let i = CssInstance::new(&*get_instance(tw, &item)?, tw, true)
+            .with_variants(&item, tw)?
+            .with_important(&item);
+        tw.record_variables(&i);
         match &i.inlineable {
             true => out.add_inline(i),
             false => {
                 out.add_trace(&i);
-                tw.objects.insert(i);
+                tw.insert_object(i);
             },
         };
     }
     out.set_mode(mode);
-    tw.bundles.insert(out.to_owned());
+    tw.insert_bundle(out.to_owned());
     Ok(out)
 }
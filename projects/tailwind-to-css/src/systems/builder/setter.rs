@@ -21,6 +21,16 @@ impl Default for TailwindBuilder {
             fonts: FontSystem::builtin(),
             preflight: PreflightSystem::default(),
             effects: EffectSystem::builtin(),
+            spacing: SpacingSystem::builtin(),
+            typography: TypographySystem::builtin(),
+            dark_mode: DarkMode::default(),
+            theme_variables: false,
+            color_format: ColorFormat::default(),
+            variables: Default::default(),
+            diagnostics: Default::default(),
+            parser: Default::default(),
+            sources: Default::default(),
+            current_source: None,
         }
     }
 }
@@ -0,0 +1,113 @@
+use super::*;
+
+/// The rules contributed by one source file, see [`TailwindBuilder::begin_source`]
+#[derive(Debug, Default)]
+pub(crate) struct SourceRecord {
+    objects: BTreeSet<CssInstance>,
+    bundles: BTreeSet<CssBundle>,
+    variables: BTreeSet<String>,
+}
+
+impl TailwindBuilder {
+    /// Record the following rules as the ones of `name`, replacing what it contributed before
+    ///
+    /// A watcher calls this before recompiling a changed file, so that the classes removed from it are dropped.
+    pub fn begin_source(&mut self, name: impl Into<String>) {
+        let name = name.into();
+        self.remove_source(&name);
+        self.current_source = Some(name);
+    }
+    /// Stop recording, the following rules are kept until [`TailwindBuilder::clear`]
+    #[inline]
+    pub fn end_source(&mut self) {
+        self.current_source = None;
+    }
+    /// Drop the rules of a deleted source, unless another source still uses them
+    ///
+    /// Rules traced outside of any source are not recorded, they are dropped too when the source also used them.
+    pub fn remove_source(&mut self, name: &str) -> bool {
+        if self.current_source.as_deref() == Some(name) {
+            self.current_source = None;
+        }
+        let record = match self.sources.remove(name) {
+            Some(s) => s,
+            None => return false,
+        };
+        for css in record.objects {
+            if !self.sources.values().any(|s| s.objects.contains(&css)) {
+                self.objects.remove(&css);
+            }
+        }
+        for css in record.bundles {
+            if !self.sources.values().any(|s| s.bundles.contains(&css)) {
+                self.bundles.remove(&css);
+            }
+        }
+        for name in record.variables {
+            if !self.sources.values().any(|s| s.variables.contains(&name)) {
+                self.variables.remove(&name);
+            }
+        }
+        true
+    }
+    /// Names of the sources with recorded rules
+    pub fn sources(&self) -> impl Iterator<Item = &str> {
+        self.sources.keys().map(|s| s.as_str())
+    }
+    pub(crate) fn insert_object(&mut self, css: CssInstance) {
+        if let Some(record) = self.current_record() {
+            record.objects.insert(css.clone());
+        }
+        self.objects.insert(css);
+    }
+    pub(crate) fn insert_bundle(&mut self, css: CssBundle) {
+        if let Some(record) = self.current_record() {
+            record.bundles.insert(css.clone());
+        }
+        self.bundles.insert(css);
+    }
+    pub(crate) fn insert_variable(&mut self, name: String) {
+        if let Some(record) = self.current_record() {
+            record.variables.insert(name.clone());
+        }
+        self.variables.insert(name);
+    }
+    fn current_record(&mut self) -> Option<&mut SourceRecord> {
+        let name = self.current_source.as_ref()?;
+        Some(self.sources.entry(name.clone()).or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TailwindBuilder;
+
+    #[test]
+    fn test_sources() {
+        let mut builder = TailwindBuilder::default();
+        builder.preflight.disable = true;
+        builder.begin_source("a.html");
+        builder.trace("p-2 m-4", false).unwrap();
+        builder.begin_source("b.html");
+        builder.trace("p-2 text-red-500", false).unwrap();
+        builder.end_source();
+        let bundle = builder.bundle().unwrap();
+        assert!(bundle.contains(".m-4") && bundle.contains(".text-red-500"));
+        // `m-4` was only used by `a.html`, `p-2` is still used by `b.html`
+        builder.begin_source("a.html");
+        builder.trace("p-2", false).unwrap();
+        builder.end_source();
+        let bundle = builder.bundle().unwrap();
+        assert!(bundle.contains(".p-2") && !bundle.contains(".m-4"));
+        assert!(builder.remove_source("b.html"));
+        let bundle = builder.bundle().unwrap();
+        assert!(bundle.contains(".p-2") && !bundle.contains(".text-red-500"));
+        assert_eq!(builder.sources().collect::<Vec<_>>(), ["a.html"]);
+        assert!(!builder.remove_source("c.html"));
+        // rules traced outside of a source are kept
+        builder.trace("m-2", false).unwrap();
+        assert!(builder.remove_source("a.html"));
+        let bundle = builder.bundle().unwrap();
+        assert!(bundle.contains(".m-2") && !bundle.contains(".p-2"));
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use super::format::percent;
 
 mod traits;
 
@@ -8,7 +9,8 @@ pub enum TailwindColor {
     RGB(Srgb),
     Themed{name: String, weight: u32, alpha: Option<f32>},
     Keyword { name: String, alpha: Option<f32> },
-    Arbitrary(TailwindArbitrary),
+    /// `[oklch(0.7_0.1_200)]`, `[var(--brand)]/50`, written as is, the alpha is mixed in with `color-mix()`
+    Arbitrary { value: TailwindArbitrary, alpha: Option<f32> },
 }
 
 impl Display for TailwindColor {
@@ -30,7 +32,13 @@ impl Display for TailwindColor {
                 }
                 Ok(())
             }
-            Self::Arbitrary(a) => a.write(f),
+            Self::Arbitrary { value, alpha } => {
+                value.write(f)?;
+                if let Some(a) = alpha {
+                    write!(f, "/{}", percent(*a))?;
+                }
+                Ok(())
+            }
             Self::Keyword { name, alpha } => {
                 match name.as_str() {
                     "transparent" => write!(f, "transparent")?,
@@ -53,36 +61,30 @@ impl TailwindColor {
     /// `white`
     pub const White: Self = Self::RGB(Srgb { red: 1.0, green: 1.0, blue: 1.0, alpha: 1.0 });
     
-    /// Parses a color pattern, now with support for opacity modifiers like `/75`.
+    /// Parses a color pattern, the opacity comes from the `/75` or `/[.75]` modifier.
     /// https://developer.mozilla.org/zh-CN/docs/Web/CSS/color_value
     pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
-        // 1. Separate the main color pattern from the opacity modifier.
-        let (main_pattern, opacity_modifier) = split_modifier(pattern);
-
-        // 2. Parse the base color from the main pattern.
-        let mut color = match main_pattern.as_slice() {
+        let mut color = match pattern {
             ["none"] | ["transparent"] => Self::from("transparent"),
             ["black"] => Self::Black,
             ["white"] => Self::White,
             [s @ ("current" | "inherit" | "initial" | "unset")] => Self::from(*s),
 
-            [] => Self::parse_arbitrary(arbitrary)?,
-            [name, weight] => Self::parse_themed(name, weight)?,
+            [] => return Self::parse_arbitrary(arbitrary),
+            [name, weight] if weight.parse::<u32>().is_ok() => Self::parse_themed(name, weight)?,
+            // nested keywords, `brand-light`
+            [name, key] => Self::Keyword { name: format!("{}-{}", name, key), alpha: None },
 
             [name] => Self::Keyword {
                 name: name.to_string(),
                 alpha: None, // The alpha logic handles this later
             },
 
-            _ => return syntax_error!("Unknown color pattern: {}", main_pattern.join("-")),
+            _ => return syntax_error!("Unknown color pattern: {}", pattern.join("-")),
         };
-
-        // 3. If an opacity modifier exists, parse and apply it.
-        if let Some(modifier_str) = opacity_modifier {
-            let alpha = modifier_str.parse::<f32>()? / 100.0;
-            color.set_alpha(alpha);
+        if let Some(modifier) = arbitrary.as_modifier() {
+            color.set_alpha(parse_alpha(modifier)?);
         }
-
         Ok(color)
     }
 
@@ -92,16 +94,56 @@ impl TailwindColor {
             TailwindColor::RGB(srgb) => srgb.alpha = alpha,
             TailwindColor::Themed { alpha: a, .. } => *a = Some(alpha),
             TailwindColor::Keyword { alpha: a, .. } => *a = Some(alpha),
-            TailwindColor::Arbitrary(_) => {
-                // Applying alpha to an already-arbitrary value is complex
-                // and often not needed, but could be implemented here if required.
-            }
+            TailwindColor::Arbitrary { alpha: a, .. } => *a = Some(alpha),
         }
     }
 
-    #[inline]
+    /// `[#243c5a]`, `[oklch(0.7_0.1_200)]`, `[color:var(--x)]` or `[theme(colors.red.500)]`, with an optional `/50` opacity
     pub fn parse_arbitrary(arbitrary: &TailwindArbitrary) -> Result<TailwindColor> {
-        Ok(Self::RGB(arbitrary.as_color()?))
+        let value = arbitrary.as_color_value();
+        let mut color = match value.strip_prefix("theme(").and_then(|s| s.strip_suffix(')')) {
+            Some(path) => Self::parse_theme_path(path)?,
+            None => match Srgb::from_str(&value) {
+                Ok(o) => Self::RGB(o),
+                Err(_) if Self::is_opaque_color(arbitrary, &value) => {
+                    Self::Arbitrary { value: TailwindArbitrary::from(arbitrary), alpha: None }
+                },
+                Err(e) => return Err(e.into()),
+            },
+        };
+        if let Some(modifier) = arbitrary.as_modifier() {
+            color.set_alpha(parse_alpha(modifier)?);
+        }
+        Ok(color)
+    }
+
+    /// Values written as is when css_color can't parse them: `var(...)`, a `color:` type hint, or the css color
+    /// functions it doesn't know. `#...`, `rgb(...)`, `hsl(...)` and `hwb(...)` must parse.
+    fn is_opaque_color(arbitrary: &TailwindArbitrary, value: &str) -> bool {
+        const OPAQUE: &[&str] = &["var(", "lab(", "lch(", "oklab(", "oklch(", "color(", "color-mix("];
+        arbitrary.as_str().starts_with("color:") || OPAQUE.iter().any(|f| value.starts_with(f))
+    }
+
+    /// `colors.red.500`, `colors.brand.light` or `colors.red.500 / 75%`
+    fn parse_theme_path(path: &str) -> Result<TailwindColor> {
+        let (path, alpha) = match path.split_once('/') {
+            Some((path, alpha)) => (path.trim(), Some(parse_alpha(alpha.trim())?)),
+            None => (path.trim(), None),
+        };
+        let names: Vec<&str> = match path.strip_prefix("colors.") {
+            Some(s) => s.split('.').collect(),
+            None => return syntax_error!("theme() only resolves colors, found: {}", path),
+        };
+        let mut color = match names.as_slice() {
+            [name, weight] if weight.parse::<u32>().is_ok() => Self::parse_themed(name, weight)?,
+            [name] | [name, "DEFAULT"] => Self::Keyword { name: name.to_string(), alpha: None },
+            [name, key] => Self::Keyword { name: format!("{}-{}", name, key), alpha: None },
+            _ => return syntax_error!("Unknown theme color: {}", path),
+        };
+        if let Some(a) = alpha {
+            color.set_alpha(a);
+        }
+        Ok(color)
     }
 
     ///
@@ -113,6 +155,21 @@ impl TailwindColor {
         Ok(Self::Themed { name, weight, alpha: None })
     }
 
+    /// Themed colors and keywords only exist in the palettes of the builder
+    pub fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        match self {
+            Self::Keyword { name, .. } if matches!(name.as_str(), "transparent" | "current" | "inherit" | "initial" | "unset") => {
+                Ok(())
+            },
+            Self::Keyword { name, .. } if ctx.palettes.try_get_keyword_color(name).is_err() => {
+                syntax_error!("Unknown color: {}", name)
+            },
+            Self::Themed { name, weight, .. } if ctx.palettes.try_get_color(name, *weight).is_err() => {
+                syntax_error!("Unknown color: {}-{}", name, weight)
+            },
+            _ => Ok(()),
+        }
+    }
     /// get class of `<color>`
     ///
     /// - https://developer.mozilla.org/zh-CN/docs/Web/CSS/color_value
@@ -126,8 +183,11 @@ impl TailwindColor {
     #[inline]
     pub fn get_properties(&self, ctx: &TailwindBuilder) -> String {
         match self {
-            Self::RGB(c) => format!("rgba({}, {}, {}, {})", 255.0 * c.red, 255.0 * c.green, 255.0 * c.blue, c.alpha),
-            Self::Arbitrary(a) => a.get_properties(),
+            Self::RGB(c) => ctx.color_format.write_color(&Oklab::from(*c)),
+            Self::Arbitrary { value, alpha: Some(a) } => {
+                format!("color-mix(in oklab, {} {}%, transparent)", value.as_color_value(), percent(*a))
+            },
+            Self::Arbitrary { value, alpha: None } => value.as_color_value(),
             Self::Keyword { name, alpha } => {
                 // First, check for special CSS keywords that should not be looked up in the palette.
                 match name.as_str() {
@@ -138,6 +198,7 @@ impl TailwindColor {
 
                 // Second, try to find the keyword in the palette system.
                 match ctx.palettes.try_get_keyword_color(name) {
+                    Ok(_) if ctx.theme_variables => theme_variable(name, *alpha),
                     Ok(color_ref) => {
                         // We got a reference, so we copy it to a mutable variable to work with.
                         let mut c = *color_ref;
@@ -145,7 +206,7 @@ impl TailwindColor {
                         if let Some(a) = alpha {
                             c.alpha = *a;
                         }
-                        format!("rgba({}, {}, {}, {})", 255.0 * c.red, 255.0 * c.green, 255.0 * c.blue, c.alpha)
+                        ctx.color_format.write_color(&c)
                     }
                     // Fallback if the keyword is not found in the palette.
                     Err(_) => "currentColor".to_string(),
@@ -153,11 +214,12 @@ impl TailwindColor {
             }
             Self::Themed { name, weight, alpha } => {
                 match ctx.palettes.try_get_color(name, *weight) {
+                    Ok(_) if ctx.theme_variables => theme_variable(&format!("{}-{}", name, weight), *alpha),
                     Ok(mut c) => {
                         if let Some(a) = alpha {
                             c.alpha = *a;
                         }
-                        format!("rgba({}, {}, {}, {})", 255.0 * c.red, 255.0 * c.green, 255.0 * c.blue, c.alpha)
+                        ctx.color_format.write_color(&c)
                     },
                     Err(_) => "currentColor".to_string(),
                 }
@@ -166,25 +228,22 @@ impl TailwindColor {
     }
 }
 
-
-/// Splits a Tailwind class pattern into its main part and an optional modifier.
-///
-/// e.g., `["red", "500/75"]` -> `(vec!["red", "500"], Some("75"))`
-/// e.g., `["red", "500"]`    -> `(vec!["red", "500"], None)`
-fn split_modifier<'a>(pattern: &'a [&'a str]) -> (Vec<&'a str>, Option<&'a str>) {
-    if let Some((last, initial_parts)) = pattern.split_last() {
-        if let Some((main, modifier)) = last.rsplit_once('/') {
-            let mut main_pattern = initial_parts.to_vec();
-            main_pattern.push(main);
-            return (main_pattern, Some(modifier));
-        }
+/// `var(--color-red-500)`, the alpha modifier is mixed in as `color-mix(in oklab, var(--color-red-500) 50%, transparent)`
+fn theme_variable(name: &str, alpha: Option<f32>) -> String {
+    match alpha {
+        Some(a) => format!("color-mix(in oklab, var(--color-{}) {}%, transparent)", name, percent(a)),
+        None => format!("var(--color-{})", name),
     }
-    // If no "/" is found, return the original pattern and no modifier.
-    (pattern.to_vec(), None)
 }
 
-
-
+/// `50`, `50%` or `[.5]` => `0.5`
+fn parse_alpha(modifier: &str) -> Result<f32> {
+    match modifier.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        Some(s) if s.ends_with('%') => Ok(s.trim_end_matches('%').parse::<f32>()? / 100.0),
+        Some(s) => Ok(s.parse::<f32>()?),
+        None => Ok(modifier.trim_end_matches('%').parse::<f32>()? / 100.0),
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -218,8 +277,8 @@ mod tests {
         // --- Test 2: Keyword with an opacity modifier ---
 
         // ACT: Parse a class name with an opacity modifier.
-        let pattern_with_alpha = ["primary/50"];
-        let color_with_alpha = TailwindColor::parse(&pattern_with_alpha, &arbitrary).unwrap();
+        let modifier = TailwindArbitrary::from("").with_modifier(Some("50"));
+        let color_with_alpha = TailwindColor::parse(&pattern, &modifier).unwrap();
 
         // ASSERT: Check that the final CSS includes the correct alpha.
         let css_output_with_alpha = color_with_alpha.get_properties(&builder);
@@ -227,4 +286,82 @@ mod tests {
         
         println!("✅ Custom keyword tests passed!");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_theme_variables() {
+        let mut builder = TailwindBuilder { theme_variables: true, ..Default::default() };
+        builder.preflight.disable = true;
+        builder.palettes.register_keyword("primary".to_string(), "#9A66FF").unwrap();
+        builder.trace("bg-red-500 text-primary/50 border-red-500", false).unwrap();
+        assert_eq!(
+            builder.bundle().unwrap(),
+            ":root{--color-primary:rgba(154, 102, 255, 1);--color-red-500:rgba(251, 44, 54, 1);}\
+            .bg-red-500{background-color:var(--color-red-500);}\
+            .border-red-500{border-color:var(--color-red-500);}\
+            .text-primary\\/50{color:color-mix(in oklab, var(--color-primary) 50%, transparent);}"
+        );
+        // a variable outside the palettes is written as is, and not declared
+        builder.clear();
+        builder.trace("bg-[var(--color-nope)]/50", false).unwrap();
+        assert_eq!(
+            builder.bundle().unwrap(),
+            ".bg-\\[var\\(--color-nope\\)\\]\\/50{background-color:color-mix(in oklab, var(--color-nope) 50%, transparent);}"
+        );
+    }
+
+    #[test]
+    fn test_color_format() {
+        let mut builder = TailwindBuilder::default();
+        let arbitrary = TailwindArbitrary::from("");
+        let red = TailwindColor::parse(&["red", "500"], &arbitrary).unwrap();
+        let faded = TailwindColor::parse(&["red", "500"], &arbitrary.clone().with_modifier(Some("50"))).unwrap();
+        let white = TailwindColor::parse(&["white"], &arbitrary).unwrap();
+        let cases = [
+            (ColorFormat::Rgba, "rgba(251, 44, 54, 1)", "rgba(251, 44, 54, 0.5)", "rgba(255, 255, 255, 1)"),
+            (ColorFormat::Hex, "#fb2c36", "#fb2c3680", "#ffffff"),
+            (ColorFormat::Rgb, "rgb(251 44 54)", "rgb(251 44 54 / 50%)", "rgb(255 255 255)"),
+            (ColorFormat::Oklch, "oklch(63.7% 0.237 25.331)", "oklch(63.7% 0.237 25.331 / 50%)", "oklch(100% 0 0)"),
+        ];
+        for (format, solid, alpha, keyword) in cases {
+            builder.color_format = format;
+            assert_eq!(red.get_properties(&builder), solid);
+            assert_eq!(faded.get_properties(&builder), alpha);
+            assert_eq!(white.get_properties(&builder), keyword);
+        }
+    }
+
+    #[test]
+    fn test_unknown_colors() {
+        let mut builder = TailwindBuilder::default();
+        builder.palettes.register_keyword("brand-light".to_string(), "#DDD6FE").unwrap();
+        assert_eq!(builder.inline("bg-brand-light").unwrap().1, "background-color:rgba(221, 214, 254, 1);");
+        assert_eq!(builder.inline("border-current").unwrap().1, "border-color:currentColor;");
+        // colors missing from the palettes are rejected instead of falling back to `currentColor`
+        for class in ["outline-custom-red", "bg-nope", "text-muted", "fill-nope-500", "from-brand-dark"] {
+            assert!(builder.inline(class).is_err(), "{}", class);
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_colors() {
+        let mut builder = TailwindBuilder::default();
+        builder.preflight.disable = true;
+        let mut inline = |class: &str| builder.inline(class).unwrap().1;
+        assert_eq!(inline("bg-[oklch(0.7_0.1_200)]"), "background-color:oklch(0.7 0.1 200);");
+        assert_eq!(inline("bg-[hsl(0,100%,50%)]"), "background-color:rgba(255, 0, 0, 1);");
+        assert_eq!(inline("bg-[color:var(--x)]"), "background-color:var(--x);");
+        assert_eq!(inline("text-[var(--brand)]/50"), "color:color-mix(in oklab, var(--brand) 50%, transparent);");
+        assert_eq!(inline("border-[theme(colors.red.500)]"), "border-color:rgba(251, 44, 54, 1);");
+        assert_eq!(inline("border-[theme(colors.red.500_/_25%)]"), "border-color:rgba(251, 44, 54, 0.25);");
+        assert_eq!(inline("text-[#ff0000]/50"), "color:rgba(255, 0, 0, 0.5);");
+        assert_eq!(inline("text-[2rem]"), "font-size:2rem;");
+        assert_eq!(inline("bg-red-500/[.37]"), "background-color:rgba(251, 44, 54, 0.37);");
+        assert_eq!(inline("bg-red-500/[37%]"), "background-color:rgba(251, 44, 54, 0.37);");
+        assert_eq!(inline("bg-[#ff0000]/[0.25]"), "background-color:rgba(255, 0, 0, 0.25);");
+        assert!(builder.inline("bg-[theme(spacing.4)]").is_err());
+        // `#...` and `rgb(...)` are never written unchecked, and theme() only finds existing palettes
+        for class in ["bg-[#zzz]", "bg-[rgb(123,_456,_123)_black]", "bg-[#0f0_var(--value)]", "bg-[theme(colors.nope.500)]"] {
+            assert!(builder.inline(class).is_err(), "{}", class);
+        }
+    }
+}
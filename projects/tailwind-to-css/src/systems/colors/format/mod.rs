@@ -0,0 +1,70 @@
+use super::*;
+
+/// How resolved colors are written into the stylesheet
+///
+/// Channels are rounded to whole bytes, alpha to 3 decimals, so the output does not depend on float noise.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColorFormat {
+    /// `rgba(251, 44, 54, 0.5)`
+    #[default]
+    Rgba,
+    /// `#fb2c36`, `#fb2c3680`
+    Hex,
+    /// `rgb(251 44 54)`, `rgb(251 44 54 / 50%)`
+    Rgb,
+    /// `oklch(63.7% 0.237 25.331)`, `oklch(63.7% 0.237 25.331 / 50%)`
+    ///
+    /// Keeps the wide gamut colors of the builtin palettes.
+    Oklch,
+}
+
+impl ColorFormat {
+    /// Write a color in this format, out of gamut colors are clamped unless written as `oklch()`
+    pub fn write_color(&self, color: &Oklab) -> String {
+        if let Self::Oklch = self {
+            return write_oklch(color);
+        }
+        let c = Srgb::from(*color);
+        let [r, g, b, a] = [c.red, c.green, c.blue, color.alpha].map(|x| (x.clamp(0.0, 1.0) * 255.0).round() as u8);
+        match self {
+            Self::Rgba => format!("rgba({}, {}, {}, {})", r, g, b, round(color.alpha.clamp(0.0, 1.0), 3)),
+            Self::Hex if a == 255 => format!("#{:02x}{:02x}{:02x}", r, g, b),
+            Self::Hex => format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a),
+            Self::Rgb if a == 255 => format!("rgb({} {} {})", r, g, b),
+            Self::Rgb => format!("rgb({} {} {} / {}%)", r, g, b, percent(color.alpha)),
+            Self::Oklch => unreachable!(),
+        }
+    }
+}
+
+fn write_oklch(color: &Oklab) -> String {
+    let chroma = color.chroma();
+    // the hue of a gray is noise
+    let (chroma, hue) = match chroma < 0.0005 {
+        true => (0.0, 0.0),
+        false => (chroma, color.hue()),
+    };
+    let lch = format!("{}% {} {}", round(color.l * 100.0, 1), round(chroma, 3), round(hue, 3));
+    match color.alpha < 1.0 {
+        true => format!("oklch({} / {}%)", lch, percent(color.alpha)),
+        false => format!("oklch({})", lch),
+    }
+}
+
+/// `0.5` => `50`
+pub(crate) fn percent(alpha: f32) -> String {
+    round(alpha.clamp(0.0, 1.0) * 100.0, 1)
+}
+
+/// Round to a number of decimals, without trailing zeros
+fn round(x: f32, digits: usize) -> String {
+    let out = format!("{:.*}", digits, x);
+    let out = match out.contains('.') {
+        true => out.trim_end_matches('0').trim_end_matches('.'),
+        false => &out,
+    };
+    match out {
+        "-0" => "0".to_string(),
+        _ => out.to_string(),
+    }
+}
@@ -1,9 +1,11 @@
 mod color;
+mod format;
+mod oklab;
 mod palette;
 mod palette_system;
 
-pub use self::{color::TailwindColor, palette::Palette, palette_system::PaletteSystem};
-use crate::{syntax_error, Result, TailwindArbitrary, TailwindBuilder};
+pub use self::{color::TailwindColor, format::ColorFormat, oklab::Oklab, palette::{Palette, PaletteRamp}, palette_system::PaletteSystem};
+use crate::{syntax_error, Result, TailwindArbitrary, TailwindBuilder, TailwindError};
 use css_color::Srgb;
 use std::{
     collections::{BTreeMap, HashMap},
@@ -0,0 +1,134 @@
+use super::*;
+
+/// A color in the OKLab space, where equal distances look like equal differences
+///
+/// <https://bottosson.github.io/posts/oklab/>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+    /// Perceived lightness, `0.0..=1.0`
+    pub l: f32,
+    /// Green-red axis
+    pub a: f32,
+    /// Blue-yellow axis
+    pub b: f32,
+    pub alpha: f32,
+}
+
+impl From<Srgb> for Oklab {
+    fn from(c: Srgb) -> Self {
+        let (r, g, b) = (to_linear(c.red), to_linear(c.green), to_linear(c.blue));
+        let l = (0.41222146 * r + 0.53633255 * g + 0.051445995 * b).cbrt();
+        let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+        let s = (0.08830246 * r + 0.28171885 * g + 0.6299787 * b).cbrt();
+        Self {
+            l: 0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+            a: 1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+            b: 0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+            alpha: c.alpha,
+        }
+    }
+}
+
+impl From<Oklab> for Srgb {
+    fn from(c: Oklab) -> Self {
+        let [red, green, blue] = c.linear_rgb().map(from_linear);
+        Srgb { red, green, blue, alpha: c.alpha }
+    }
+}
+
+impl Oklab {
+    /// Mix two colors, `t = 0.0` is `self` and `t = 1.0` is `other`
+    pub fn mix(&self, other: &Self, t: f32) -> Self {
+        Self {
+            l: self.l + (other.l - self.l) * t,
+            a: self.a + (other.a - self.a) * t,
+            b: self.b + (other.b - self.b) * t,
+            alpha: self.alpha + (other.alpha - self.alpha) * t,
+        }
+    }
+    /// A color from lightness, chroma and hue in degrees, the polar form OKLCH
+    pub fn from_lch(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        let h = h.to_radians();
+        Self { l, a: c * h.cos(), b: c * h.sin(), alpha }
+    }
+    /// Can the color be shown in sRGB without clamping the channels
+    pub fn in_gamut(&self) -> bool {
+        self.linear_rgb().iter().all(|x| (-0.0001..=1.0001).contains(x))
+    }
+    /// The colorfulness in OKLCH
+    pub fn chroma(&self) -> f32 {
+        self.a.hypot(self.b)
+    }
+    /// The hue in OKLCH, in degrees `0.0..360.0`
+    pub fn hue(&self) -> f32 {
+        self.b.atan2(self.a).to_degrees().rem_euclid(360.0)
+    }
+    fn linear_rgb(&self) -> [f32; 3] {
+        let l = (self.l + 0.39633778 * self.a + 0.21580376 * self.b).powi(3);
+        let m = (self.l - 0.105561346 * self.a - 0.06385417 * self.b).powi(3);
+        let s = (self.l - 0.08948418 * self.a - 1.2914855 * self.b).powi(3);
+        [
+            4.0767417 * l - 3.3077116 * m + 0.23096994 * s,
+            -1.268438 * l + 2.6097574 * m - 0.34131938 * s,
+            -0.0041960863 * l - 0.7034186 * m + 1.7076147 * s,
+        ]
+    }
+}
+
+impl FromStr for Oklab {
+    type Err = TailwindError;
+    /// `oklch(63.7% 0.237 25.331)`, `oklab(0.637 0.214 0.102 / 50%)`, or any color known to css_color
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Some(args) = s.strip_prefix("oklch(").and_then(|s| s.strip_suffix(')')) {
+            let ([l, c, h], alpha) = split_channels(args)?;
+            return Ok(Self::from_lch(channel(l, 1.0)?, channel(c, 0.4)?, channel(h.trim_end_matches("deg"), 1.0)?, alpha));
+        }
+        if let Some(args) = s.strip_prefix("oklab(").and_then(|s| s.strip_suffix(')')) {
+            let ([l, a, b], alpha) = split_channels(args)?;
+            return Ok(Self { l: channel(l, 1.0)?, a: channel(a, 0.4)?, b: channel(b, 0.4)?, alpha });
+        }
+        Ok(Self::from(Srgb::from_str(s)?))
+    }
+}
+
+/// The three channels and the alpha of `l c h / alpha`
+fn split_channels(args: &str) -> Result<([&str; 3], f32)> {
+    let (channels, alpha) = match args.split_once('/') {
+        Some((channels, alpha)) => (channels, channel(alpha.trim(), 1.0)?),
+        None => (args, 1.0),
+    };
+    match channels.split_whitespace().collect::<Vec<_>>().as_slice() {
+        [x, y, z] => Ok(([x, y, z], alpha)),
+        _ => syntax_error!("Expected three color channels, found: {}", args),
+    }
+}
+
+/// A number, or a percentage of `full`, `none` is zero
+fn channel(s: &str, full: f32) -> Result<f32> {
+    let value = match s.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().map(|p| p / 100.0 * full),
+        None if s == "none" => Ok(0.0),
+        None => s.parse::<f32>(),
+    };
+    match value {
+        Ok(o) => Ok(o),
+        Err(_) => syntax_error!("Invalid color channel: {}", s),
+    }
+}
+
+fn to_linear(x: f32) -> f32 {
+    match x <= 0.04045 {
+        true => x / 12.92,
+        false => ((x + 0.055) / 1.055).powf(2.4),
+    }
+}
+
+/// Out of gamut channels are clamped, and rounded to 8 bits like the hex colors
+fn from_linear(x: f32) -> f32 {
+    let x = match x <= 0.0031308 {
+        true => 12.92 * x,
+        false => 1.055 * x.powf(1.0 / 2.4) - 0.055,
+    };
+    (x.clamp(0.0, 1.0) * 255.0).round() / 255.0
+}
@@ -5,7 +5,8 @@ getColorMap[color_] := <|
     "map" -> Cases[color, {XMLElement["div", {__}, {n_}], XMLElement["div", {__}, {a_}]} :> {n, a}, Infinity]
 |>;
 asMarkdown = StringRiffle[TemplateApply["<span style=\"color:`2`\">`1`</span>", #]& /@ #map, ",\n    ///"]&;
-asRust = StringRiffle[TemplateApply["colors.insert(`1`,Srgb::from_str(\"`2`\").unwrap())", #]& /@ #map, ";\n        "]&;
+oklchArgs[s_] := With[{v = StringSplit@StringDelete[s, {"oklch(", ")"}]}, StringRiffle[{ToString@N[ToExpression@StringDelete[v[[1]], "%"] / 100], v[[2]], v[[3]]}, ", "]];
+asRust = StringRiffle[TemplateApply["colors.insert(`1`, Oklab::from_lch(`2`, 1.0))", {#[[1]], oklchArgs[#[[2]]]}]& /@ #map, ";\n        "]&;
 buildFunction = TemplateApply["\
     /// ## `Name`
     ///`asMarkdown`
@@ -24,14 +25,14 @@ buildFunction = TemplateApply["\
 ]&;
 
 
-colors = Import["https://tailwindcss.com/docs/customizing-colors", {"XHTML", "XMLObject"}];
+colors = Import["https://tailwindcss.com/docs/colors", {"XHTML", "XMLObject"}];
 colors = Cases[colors, XMLElement["div", {"class" -> "grid grid-cols-1 gap-8"}, xml___] :> xml, Infinity] // Flatten;
 colors = getColorMap /@ colors;
 codegen = StringJoin[Flatten[{
     "\
 use super::*;
-/// Builtin colors
-/// https://tailwindcss.com/docs/customizing-colors
+/// Builtin colors, in the OKLCH values of tailwind v4
+/// <https://tailwindcss.com/docs/colors>
 impl Palette {
 ",
     buildFunction /@ colors,
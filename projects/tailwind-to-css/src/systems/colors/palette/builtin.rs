@@ -1,555 +1,599 @@
 use super::*;
-/// Builtin colors
-/// https://tailwindcss.com/docs/customizing-colors
+/// Builtin colors, in the OKLCH values of tailwind v4
+/// <https://tailwindcss.com/docs/colors>
 impl Palette {
     /// ## SLATE
-    /// <span style="color:#F8FAFC">50</span>,
-    /// <span style="color:#F1F5F9">100</span>,
-    /// <span style="color:#E2E8F0">200</span>,
-    /// <span style="color:#CBD5E1">300</span>,
-    /// <span style="color:#94A3B8">400</span>,
-    /// <span style="color:#64748B">500</span>,
-    /// <span style="color:#475569">600</span>,
-    /// <span style="color:#334155">700</span>,
-    /// <span style="color:#1E293B">800</span>,
-    /// <span style="color:#0F172A">900</span>
+    /// <span style="color:oklch(98.4% 0.003 247.858)">50</span>,
+    /// <span style="color:oklch(96.8% 0.007 247.896)">100</span>,
+    /// <span style="color:oklch(92.9% 0.013 255.508)">200</span>,
+    /// <span style="color:oklch(86.9% 0.022 252.894)">300</span>,
+    /// <span style="color:oklch(70.4% 0.04 256.788)">400</span>,
+    /// <span style="color:oklch(55.4% 0.046 257.417)">500</span>,
+    /// <span style="color:oklch(44.6% 0.043 257.281)">600</span>,
+    /// <span style="color:oklch(37.2% 0.044 257.287)">700</span>,
+    /// <span style="color:oklch(27.9% 0.041 260.031)">800</span>,
+    /// <span style="color:oklch(20.8% 0.042 265.755)">900</span>,
+    /// <span style="color:oklch(12.9% 0.042 264.695)">950</span>
     pub fn slate() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#F8FAFC").unwrap());
-        colors.insert(100, Srgb::from_str("#F1F5F9").unwrap());
-        colors.insert(200, Srgb::from_str("#E2E8F0").unwrap());
-        colors.insert(300, Srgb::from_str("#CBD5E1").unwrap());
-        colors.insert(400, Srgb::from_str("#94A3B8").unwrap());
-        colors.insert(500, Srgb::from_str("#64748B").unwrap());
-        colors.insert(600, Srgb::from_str("#475569").unwrap());
-        colors.insert(700, Srgb::from_str("#334155").unwrap());
-        colors.insert(800, Srgb::from_str("#1E293B").unwrap());
-        colors.insert(900, Srgb::from_str("#0F172A").unwrap());
+        colors.insert(50, Oklab::from_lch(0.984, 0.003, 247.858, 1.0));
+        colors.insert(100, Oklab::from_lch(0.968, 0.007, 247.896, 1.0));
+        colors.insert(200, Oklab::from_lch(0.929, 0.013, 255.508, 1.0));
+        colors.insert(300, Oklab::from_lch(0.869, 0.022, 252.894, 1.0));
+        colors.insert(400, Oklab::from_lch(0.704, 0.04, 256.788, 1.0));
+        colors.insert(500, Oklab::from_lch(0.554, 0.046, 257.417, 1.0));
+        colors.insert(600, Oklab::from_lch(0.446, 0.043, 257.281, 1.0));
+        colors.insert(700, Oklab::from_lch(0.372, 0.044, 257.287, 1.0));
+        colors.insert(800, Oklab::from_lch(0.279, 0.041, 260.031, 1.0));
+        colors.insert(900, Oklab::from_lch(0.208, 0.042, 265.755, 1.0));
+        colors.insert(950, Oklab::from_lch(0.129, 0.042, 264.695, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## GRAY
-    /// <span style="color:#F9FAFB">50</span>,
-    /// <span style="color:#F3F4F6">100</span>,
-    /// <span style="color:#E5E7EB">200</span>,
-    /// <span style="color:#D1D5DB">300</span>,
-    /// <span style="color:#9CA3AF">400</span>,
-    /// <span style="color:#6B7280">500</span>,
-    /// <span style="color:#4B5563">600</span>,
-    /// <span style="color:#374151">700</span>,
-    /// <span style="color:#1F2937">800</span>,
-    /// <span style="color:#111827">900</span>
+    /// <span style="color:oklch(98.5% 0.002 247.839)">50</span>,
+    /// <span style="color:oklch(96.7% 0.003 264.542)">100</span>,
+    /// <span style="color:oklch(92.8% 0.006 264.531)">200</span>,
+    /// <span style="color:oklch(87.2% 0.01 258.338)">300</span>,
+    /// <span style="color:oklch(70.7% 0.022 261.325)">400</span>,
+    /// <span style="color:oklch(55.1% 0.027 264.364)">500</span>,
+    /// <span style="color:oklch(44.6% 0.03 256.802)">600</span>,
+    /// <span style="color:oklch(37.3% 0.034 259.733)">700</span>,
+    /// <span style="color:oklch(27.8% 0.033 256.848)">800</span>,
+    /// <span style="color:oklch(21% 0.034 264.665)">900</span>,
+    /// <span style="color:oklch(13% 0.028 261.692)">950</span>
     pub fn gray() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#F9FAFB").unwrap());
-        colors.insert(100, Srgb::from_str("#F3F4F6").unwrap());
-        colors.insert(200, Srgb::from_str("#E5E7EB").unwrap());
-        colors.insert(300, Srgb::from_str("#D1D5DB").unwrap());
-        colors.insert(400, Srgb::from_str("#9CA3AF").unwrap());
-        colors.insert(500, Srgb::from_str("#6B7280").unwrap());
-        colors.insert(600, Srgb::from_str("#4B5563").unwrap());
-        colors.insert(700, Srgb::from_str("#374151").unwrap());
-        colors.insert(800, Srgb::from_str("#1F2937").unwrap());
-        colors.insert(900, Srgb::from_str("#111827").unwrap());
+        colors.insert(50, Oklab::from_lch(0.985, 0.002, 247.839, 1.0));
+        colors.insert(100, Oklab::from_lch(0.967, 0.003, 264.542, 1.0));
+        colors.insert(200, Oklab::from_lch(0.928, 0.006, 264.531, 1.0));
+        colors.insert(300, Oklab::from_lch(0.872, 0.01, 258.338, 1.0));
+        colors.insert(400, Oklab::from_lch(0.707, 0.022, 261.325, 1.0));
+        colors.insert(500, Oklab::from_lch(0.551, 0.027, 264.364, 1.0));
+        colors.insert(600, Oklab::from_lch(0.446, 0.03, 256.802, 1.0));
+        colors.insert(700, Oklab::from_lch(0.373, 0.034, 259.733, 1.0));
+        colors.insert(800, Oklab::from_lch(0.278, 0.033, 256.848, 1.0));
+        colors.insert(900, Oklab::from_lch(0.21, 0.034, 264.665, 1.0));
+        colors.insert(950, Oklab::from_lch(0.13, 0.028, 261.692, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## ZINC
-    /// <span style="color:#FAFAFA">50</span>,
-    /// <span style="color:#F4F4F5">100</span>,
-    /// <span style="color:#E4E4E7">200</span>,
-    /// <span style="color:#D4D4D8">300</span>,
-    /// <span style="color:#A1A1AA">400</span>,
-    /// <span style="color:#71717A">500</span>,
-    /// <span style="color:#52525B">600</span>,
-    /// <span style="color:#3F3F46">700</span>,
-    /// <span style="color:#27272A">800</span>,
-    /// <span style="color:#18181B">900</span>
+    /// <span style="color:oklch(98.5% 0 0)">50</span>,
+    /// <span style="color:oklch(96.7% 0.001 286.375)">100</span>,
+    /// <span style="color:oklch(92% 0.004 286.32)">200</span>,
+    /// <span style="color:oklch(87.1% 0.006 286.286)">300</span>,
+    /// <span style="color:oklch(70.5% 0.015 286.067)">400</span>,
+    /// <span style="color:oklch(55.2% 0.016 285.938)">500</span>,
+    /// <span style="color:oklch(44.2% 0.017 285.786)">600</span>,
+    /// <span style="color:oklch(37% 0.013 285.805)">700</span>,
+    /// <span style="color:oklch(27.4% 0.006 286.033)">800</span>,
+    /// <span style="color:oklch(21% 0.006 285.885)">900</span>,
+    /// <span style="color:oklch(14.1% 0.005 285.823)">950</span>
     pub fn zinc() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FAFAFA").unwrap());
-        colors.insert(100, Srgb::from_str("#F4F4F5").unwrap());
-        colors.insert(200, Srgb::from_str("#E4E4E7").unwrap());
-        colors.insert(300, Srgb::from_str("#D4D4D8").unwrap());
-        colors.insert(400, Srgb::from_str("#A1A1AA").unwrap());
-        colors.insert(500, Srgb::from_str("#71717A").unwrap());
-        colors.insert(600, Srgb::from_str("#52525B").unwrap());
-        colors.insert(700, Srgb::from_str("#3F3F46").unwrap());
-        colors.insert(800, Srgb::from_str("#27272A").unwrap());
-        colors.insert(900, Srgb::from_str("#18181B").unwrap());
+        colors.insert(50, Oklab::from_lch(0.985, 0.0, 0.0, 1.0));
+        colors.insert(100, Oklab::from_lch(0.967, 0.001, 286.375, 1.0));
+        colors.insert(200, Oklab::from_lch(0.92, 0.004, 286.32, 1.0));
+        colors.insert(300, Oklab::from_lch(0.871, 0.006, 286.286, 1.0));
+        colors.insert(400, Oklab::from_lch(0.705, 0.015, 286.067, 1.0));
+        colors.insert(500, Oklab::from_lch(0.552, 0.016, 285.938, 1.0));
+        colors.insert(600, Oklab::from_lch(0.442, 0.017, 285.786, 1.0));
+        colors.insert(700, Oklab::from_lch(0.37, 0.013, 285.805, 1.0));
+        colors.insert(800, Oklab::from_lch(0.274, 0.006, 286.033, 1.0));
+        colors.insert(900, Oklab::from_lch(0.21, 0.006, 285.885, 1.0));
+        colors.insert(950, Oklab::from_lch(0.141, 0.005, 285.823, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## NEUTRAL
-    /// <span style="color:#FAFAFA">50</span>,
-    /// <span style="color:#F5F5F5">100</span>,
-    /// <span style="color:#E5E5E5">200</span>,
-    /// <span style="color:#D4D4D4">300</span>,
-    /// <span style="color:#A3A3A3">400</span>,
-    /// <span style="color:#737373">500</span>,
-    /// <span style="color:#525252">600</span>,
-    /// <span style="color:#404040">700</span>,
-    /// <span style="color:#262626">800</span>,
-    /// <span style="color:#171717">900</span>
+    /// <span style="color:oklch(98.5% 0 0)">50</span>,
+    /// <span style="color:oklch(97% 0 0)">100</span>,
+    /// <span style="color:oklch(92.2% 0 0)">200</span>,
+    /// <span style="color:oklch(87% 0 0)">300</span>,
+    /// <span style="color:oklch(70.8% 0 0)">400</span>,
+    /// <span style="color:oklch(55.6% 0 0)">500</span>,
+    /// <span style="color:oklch(43.9% 0 0)">600</span>,
+    /// <span style="color:oklch(37.1% 0 0)">700</span>,
+    /// <span style="color:oklch(26.9% 0 0)">800</span>,
+    /// <span style="color:oklch(20.5% 0 0)">900</span>,
+    /// <span style="color:oklch(14.5% 0 0)">950</span>
     pub fn neutral() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FAFAFA").unwrap());
-        colors.insert(100, Srgb::from_str("#F5F5F5").unwrap());
-        colors.insert(200, Srgb::from_str("#E5E5E5").unwrap());
-        colors.insert(300, Srgb::from_str("#D4D4D4").unwrap());
-        colors.insert(400, Srgb::from_str("#A3A3A3").unwrap());
-        colors.insert(500, Srgb::from_str("#737373").unwrap());
-        colors.insert(600, Srgb::from_str("#525252").unwrap());
-        colors.insert(700, Srgb::from_str("#404040").unwrap());
-        colors.insert(800, Srgb::from_str("#262626").unwrap());
-        colors.insert(900, Srgb::from_str("#171717").unwrap());
+        colors.insert(50, Oklab::from_lch(0.985, 0.0, 0.0, 1.0));
+        colors.insert(100, Oklab::from_lch(0.97, 0.0, 0.0, 1.0));
+        colors.insert(200, Oklab::from_lch(0.922, 0.0, 0.0, 1.0));
+        colors.insert(300, Oklab::from_lch(0.87, 0.0, 0.0, 1.0));
+        colors.insert(400, Oklab::from_lch(0.708, 0.0, 0.0, 1.0));
+        colors.insert(500, Oklab::from_lch(0.556, 0.0, 0.0, 1.0));
+        colors.insert(600, Oklab::from_lch(0.439, 0.0, 0.0, 1.0));
+        colors.insert(700, Oklab::from_lch(0.371, 0.0, 0.0, 1.0));
+        colors.insert(800, Oklab::from_lch(0.269, 0.0, 0.0, 1.0));
+        colors.insert(900, Oklab::from_lch(0.205, 0.0, 0.0, 1.0));
+        colors.insert(950, Oklab::from_lch(0.145, 0.0, 0.0, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## STONE
-    /// <span style="color:#FAFAF9">50</span>,
-    /// <span style="color:#F5F5F4">100</span>,
-    /// <span style="color:#E7E5E4">200</span>,
-    /// <span style="color:#D6D3D1">300</span>,
-    /// <span style="color:#A8A29E">400</span>,
-    /// <span style="color:#78716C">500</span>,
-    /// <span style="color:#57534E">600</span>,
-    /// <span style="color:#44403C">700</span>,
-    /// <span style="color:#292524">800</span>,
-    /// <span style="color:#1C1917">900</span>
+    /// <span style="color:oklch(98.5% 0.001 106.423)">50</span>,
+    /// <span style="color:oklch(97% 0.001 106.424)">100</span>,
+    /// <span style="color:oklch(92.3% 0.003 48.717)">200</span>,
+    /// <span style="color:oklch(86.9% 0.005 56.366)">300</span>,
+    /// <span style="color:oklch(70.9% 0.01 56.259)">400</span>,
+    /// <span style="color:oklch(55.3% 0.013 58.071)">500</span>,
+    /// <span style="color:oklch(44.4% 0.011 73.639)">600</span>,
+    /// <span style="color:oklch(37.4% 0.01 67.558)">700</span>,
+    /// <span style="color:oklch(26.8% 0.007 34.298)">800</span>,
+    /// <span style="color:oklch(21.6% 0.006 56.043)">900</span>,
+    /// <span style="color:oklch(14.7% 0.004 49.25)">950</span>
     pub fn stone() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FAFAF9").unwrap());
-        colors.insert(100, Srgb::from_str("#F5F5F4").unwrap());
-        colors.insert(200, Srgb::from_str("#E7E5E4").unwrap());
-        colors.insert(300, Srgb::from_str("#D6D3D1").unwrap());
-        colors.insert(400, Srgb::from_str("#A8A29E").unwrap());
-        colors.insert(500, Srgb::from_str("#78716C").unwrap());
-        colors.insert(600, Srgb::from_str("#57534E").unwrap());
-        colors.insert(700, Srgb::from_str("#44403C").unwrap());
-        colors.insert(800, Srgb::from_str("#292524").unwrap());
-        colors.insert(900, Srgb::from_str("#1C1917").unwrap());
+        colors.insert(50, Oklab::from_lch(0.985, 0.001, 106.423, 1.0));
+        colors.insert(100, Oklab::from_lch(0.97, 0.001, 106.424, 1.0));
+        colors.insert(200, Oklab::from_lch(0.923, 0.003, 48.717, 1.0));
+        colors.insert(300, Oklab::from_lch(0.869, 0.005, 56.366, 1.0));
+        colors.insert(400, Oklab::from_lch(0.709, 0.01, 56.259, 1.0));
+        colors.insert(500, Oklab::from_lch(0.553, 0.013, 58.071, 1.0));
+        colors.insert(600, Oklab::from_lch(0.444, 0.011, 73.639, 1.0));
+        colors.insert(700, Oklab::from_lch(0.374, 0.01, 67.558, 1.0));
+        colors.insert(800, Oklab::from_lch(0.268, 0.007, 34.298, 1.0));
+        colors.insert(900, Oklab::from_lch(0.216, 0.006, 56.043, 1.0));
+        colors.insert(950, Oklab::from_lch(0.147, 0.004, 49.25, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## RED
-    /// <span style="color:#FEF2F2">50</span>,
-    /// <span style="color:#FEE2E2">100</span>,
-    /// <span style="color:#FECACA">200</span>,
-    /// <span style="color:#FCA5A5">300</span>,
-    /// <span style="color:#F87171">400</span>,
-    /// <span style="color:#EF4444">500</span>,
-    /// <span style="color:#DC2626">600</span>,
-    /// <span style="color:#B91C1C">700</span>,
-    /// <span style="color:#991B1B">800</span>,
-    /// <span style="color:#7F1D1D">900</span>
+    /// <span style="color:oklch(97.1% 0.013 17.38)">50</span>,
+    /// <span style="color:oklch(93.6% 0.032 17.717)">100</span>,
+    /// <span style="color:oklch(88.5% 0.062 18.334)">200</span>,
+    /// <span style="color:oklch(80.8% 0.114 19.571)">300</span>,
+    /// <span style="color:oklch(70.4% 0.191 22.216)">400</span>,
+    /// <span style="color:oklch(63.7% 0.237 25.331)">500</span>,
+    /// <span style="color:oklch(57.7% 0.245 27.325)">600</span>,
+    /// <span style="color:oklch(50.5% 0.213 27.518)">700</span>,
+    /// <span style="color:oklch(44.4% 0.177 26.899)">800</span>,
+    /// <span style="color:oklch(39.6% 0.141 25.723)">900</span>,
+    /// <span style="color:oklch(25.8% 0.092 26.042)">950</span>
     pub fn red() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FEF2F2").unwrap());
-        colors.insert(100, Srgb::from_str("#FEE2E2").unwrap());
-        colors.insert(200, Srgb::from_str("#FECACA").unwrap());
-        colors.insert(300, Srgb::from_str("#FCA5A5").unwrap());
-        colors.insert(400, Srgb::from_str("#F87171").unwrap());
-        colors.insert(500, Srgb::from_str("#EF4444").unwrap());
-        colors.insert(600, Srgb::from_str("#DC2626").unwrap());
-        colors.insert(700, Srgb::from_str("#B91C1C").unwrap());
-        colors.insert(800, Srgb::from_str("#991B1B").unwrap());
-        colors.insert(900, Srgb::from_str("#7F1D1D").unwrap());
+        colors.insert(50, Oklab::from_lch(0.971, 0.013, 17.38, 1.0));
+        colors.insert(100, Oklab::from_lch(0.936, 0.032, 17.717, 1.0));
+        colors.insert(200, Oklab::from_lch(0.885, 0.062, 18.334, 1.0));
+        colors.insert(300, Oklab::from_lch(0.808, 0.114, 19.571, 1.0));
+        colors.insert(400, Oklab::from_lch(0.704, 0.191, 22.216, 1.0));
+        colors.insert(500, Oklab::from_lch(0.637, 0.237, 25.331, 1.0));
+        colors.insert(600, Oklab::from_lch(0.577, 0.245, 27.325, 1.0));
+        colors.insert(700, Oklab::from_lch(0.505, 0.213, 27.518, 1.0));
+        colors.insert(800, Oklab::from_lch(0.444, 0.177, 26.899, 1.0));
+        colors.insert(900, Oklab::from_lch(0.396, 0.141, 25.723, 1.0));
+        colors.insert(950, Oklab::from_lch(0.258, 0.092, 26.042, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## ORANGE
-    /// <span style="color:#FFF7ED">50</span>,
-    /// <span style="color:#FFEDD5">100</span>,
-    /// <span style="color:#FED7AA">200</span>,
-    /// <span style="color:#FDBA74">300</span>,
-    /// <span style="color:#FB923C">400</span>,
-    /// <span style="color:#F97316">500</span>,
-    /// <span style="color:#EA580C">600</span>,
-    /// <span style="color:#C2410C">700</span>,
-    /// <span style="color:#9A3412">800</span>,
-    /// <span style="color:#7C2D12">900</span>
+    /// <span style="color:oklch(98% 0.016 73.684)">50</span>,
+    /// <span style="color:oklch(95.4% 0.038 75.164)">100</span>,
+    /// <span style="color:oklch(90.1% 0.076 70.697)">200</span>,
+    /// <span style="color:oklch(83.7% 0.128 66.29)">300</span>,
+    /// <span style="color:oklch(75% 0.183 55.934)">400</span>,
+    /// <span style="color:oklch(70.5% 0.213 47.604)">500</span>,
+    /// <span style="color:oklch(64.6% 0.222 41.116)">600</span>,
+    /// <span style="color:oklch(55.3% 0.195 38.402)">700</span>,
+    /// <span style="color:oklch(47% 0.157 37.304)">800</span>,
+    /// <span style="color:oklch(40.8% 0.123 38.172)">900</span>,
+    /// <span style="color:oklch(26.6% 0.079 36.259)">950</span>
     pub fn orange() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FFF7ED").unwrap());
-        colors.insert(100, Srgb::from_str("#FFEDD5").unwrap());
-        colors.insert(200, Srgb::from_str("#FED7AA").unwrap());
-        colors.insert(300, Srgb::from_str("#FDBA74").unwrap());
-        colors.insert(400, Srgb::from_str("#FB923C").unwrap());
-        colors.insert(500, Srgb::from_str("#F97316").unwrap());
-        colors.insert(600, Srgb::from_str("#EA580C").unwrap());
-        colors.insert(700, Srgb::from_str("#C2410C").unwrap());
-        colors.insert(800, Srgb::from_str("#9A3412").unwrap());
-        colors.insert(900, Srgb::from_str("#7C2D12").unwrap());
+        colors.insert(50, Oklab::from_lch(0.98, 0.016, 73.684, 1.0));
+        colors.insert(100, Oklab::from_lch(0.954, 0.038, 75.164, 1.0));
+        colors.insert(200, Oklab::from_lch(0.901, 0.076, 70.697, 1.0));
+        colors.insert(300, Oklab::from_lch(0.837, 0.128, 66.29, 1.0));
+        colors.insert(400, Oklab::from_lch(0.75, 0.183, 55.934, 1.0));
+        colors.insert(500, Oklab::from_lch(0.705, 0.213, 47.604, 1.0));
+        colors.insert(600, Oklab::from_lch(0.646, 0.222, 41.116, 1.0));
+        colors.insert(700, Oklab::from_lch(0.553, 0.195, 38.402, 1.0));
+        colors.insert(800, Oklab::from_lch(0.47, 0.157, 37.304, 1.0));
+        colors.insert(900, Oklab::from_lch(0.408, 0.123, 38.172, 1.0));
+        colors.insert(950, Oklab::from_lch(0.266, 0.079, 36.259, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## AMBER
-    /// <span style="color:#FFFBEB">50</span>,
-    /// <span style="color:#FEF3C7">100</span>,
-    /// <span style="color:#FDE68A">200</span>,
-    /// <span style="color:#FCD34D">300</span>,
-    /// <span style="color:#FBBF24">400</span>,
-    /// <span style="color:#F59E0B">500</span>,
-    /// <span style="color:#D97706">600</span>,
-    /// <span style="color:#B45309">700</span>,
-    /// <span style="color:#92400E">800</span>,
-    /// <span style="color:#78350F">900</span>
+    /// <span style="color:oklch(98.7% 0.022 95.277)">50</span>,
+    /// <span style="color:oklch(96.2% 0.059 95.617)">100</span>,
+    /// <span style="color:oklch(92.4% 0.12 95.746)">200</span>,
+    /// <span style="color:oklch(87.9% 0.169 91.605)">300</span>,
+    /// <span style="color:oklch(82.8% 0.189 84.429)">400</span>,
+    /// <span style="color:oklch(76.9% 0.188 70.08)">500</span>,
+    /// <span style="color:oklch(66.6% 0.179 58.318)">600</span>,
+    /// <span style="color:oklch(55.5% 0.163 48.998)">700</span>,
+    /// <span style="color:oklch(47.3% 0.137 46.201)">800</span>,
+    /// <span style="color:oklch(41.4% 0.112 45.904)">900</span>,
+    /// <span style="color:oklch(27.9% 0.077 45.635)">950</span>
     pub fn amber() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FFFBEB").unwrap());
-        colors.insert(100, Srgb::from_str("#FEF3C7").unwrap());
-        colors.insert(200, Srgb::from_str("#FDE68A").unwrap());
-        colors.insert(300, Srgb::from_str("#FCD34D").unwrap());
-        colors.insert(400, Srgb::from_str("#FBBF24").unwrap());
-        colors.insert(500, Srgb::from_str("#F59E0B").unwrap());
-        colors.insert(600, Srgb::from_str("#D97706").unwrap());
-        colors.insert(700, Srgb::from_str("#B45309").unwrap());
-        colors.insert(800, Srgb::from_str("#92400E").unwrap());
-        colors.insert(900, Srgb::from_str("#78350F").unwrap());
+        colors.insert(50, Oklab::from_lch(0.987, 0.022, 95.277, 1.0));
+        colors.insert(100, Oklab::from_lch(0.962, 0.059, 95.617, 1.0));
+        colors.insert(200, Oklab::from_lch(0.924, 0.12, 95.746, 1.0));
+        colors.insert(300, Oklab::from_lch(0.879, 0.169, 91.605, 1.0));
+        colors.insert(400, Oklab::from_lch(0.828, 0.189, 84.429, 1.0));
+        colors.insert(500, Oklab::from_lch(0.769, 0.188, 70.08, 1.0));
+        colors.insert(600, Oklab::from_lch(0.666, 0.179, 58.318, 1.0));
+        colors.insert(700, Oklab::from_lch(0.555, 0.163, 48.998, 1.0));
+        colors.insert(800, Oklab::from_lch(0.473, 0.137, 46.201, 1.0));
+        colors.insert(900, Oklab::from_lch(0.414, 0.112, 45.904, 1.0));
+        colors.insert(950, Oklab::from_lch(0.279, 0.077, 45.635, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## YELLOW
-    /// <span style="color:#FEFCE8">50</span>,
-    /// <span style="color:#FEF9C3">100</span>,
-    /// <span style="color:#FEF08A">200</span>,
-    /// <span style="color:#FDE047">300</span>,
-    /// <span style="color:#FACC15">400</span>,
-    /// <span style="color:#EAB308">500</span>,
-    /// <span style="color:#CA8A04">600</span>,
-    /// <span style="color:#A16207">700</span>,
-    /// <span style="color:#854D0E">800</span>,
-    /// <span style="color:#713F12">900</span>
+    /// <span style="color:oklch(98.7% 0.026 102.212)">50</span>,
+    /// <span style="color:oklch(97.3% 0.071 103.193)">100</span>,
+    /// <span style="color:oklch(94.5% 0.129 101.54)">200</span>,
+    /// <span style="color:oklch(90.5% 0.182 98.111)">300</span>,
+    /// <span style="color:oklch(85.2% 0.199 91.936)">400</span>,
+    /// <span style="color:oklch(79.5% 0.184 86.047)">500</span>,
+    /// <span style="color:oklch(68.1% 0.162 75.834)">600</span>,
+    /// <span style="color:oklch(55.4% 0.135 66.442)">700</span>,
+    /// <span style="color:oklch(47.6% 0.114 61.907)">800</span>,
+    /// <span style="color:oklch(42.1% 0.095 57.708)">900</span>,
+    /// <span style="color:oklch(28.6% 0.066 53.813)">950</span>
     pub fn yellow() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FEFCE8").unwrap());
-        colors.insert(100, Srgb::from_str("#FEF9C3").unwrap());
-        colors.insert(200, Srgb::from_str("#FEF08A").unwrap());
-        colors.insert(300, Srgb::from_str("#FDE047").unwrap());
-        colors.insert(400, Srgb::from_str("#FACC15").unwrap());
-        colors.insert(500, Srgb::from_str("#EAB308").unwrap());
-        colors.insert(600, Srgb::from_str("#CA8A04").unwrap());
-        colors.insert(700, Srgb::from_str("#A16207").unwrap());
-        colors.insert(800, Srgb::from_str("#854D0E").unwrap());
-        colors.insert(900, Srgb::from_str("#713F12").unwrap());
+        colors.insert(50, Oklab::from_lch(0.987, 0.026, 102.212, 1.0));
+        colors.insert(100, Oklab::from_lch(0.973, 0.071, 103.193, 1.0));
+        colors.insert(200, Oklab::from_lch(0.945, 0.129, 101.54, 1.0));
+        colors.insert(300, Oklab::from_lch(0.905, 0.182, 98.111, 1.0));
+        colors.insert(400, Oklab::from_lch(0.852, 0.199, 91.936, 1.0));
+        colors.insert(500, Oklab::from_lch(0.795, 0.184, 86.047, 1.0));
+        colors.insert(600, Oklab::from_lch(0.681, 0.162, 75.834, 1.0));
+        colors.insert(700, Oklab::from_lch(0.554, 0.135, 66.442, 1.0));
+        colors.insert(800, Oklab::from_lch(0.476, 0.114, 61.907, 1.0));
+        colors.insert(900, Oklab::from_lch(0.421, 0.095, 57.708, 1.0));
+        colors.insert(950, Oklab::from_lch(0.286, 0.066, 53.813, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## LIME
-    /// <span style="color:#F7FEE7">50</span>,
-    /// <span style="color:#ECFCCB">100</span>,
-    /// <span style="color:#D9F99D">200</span>,
-    /// <span style="color:#BEF264">300</span>,
-    /// <span style="color:#A3E635">400</span>,
-    /// <span style="color:#84CC16">500</span>,
-    /// <span style="color:#65A30D">600</span>,
-    /// <span style="color:#4D7C0F">700</span>,
-    /// <span style="color:#3F6212">800</span>,
-    /// <span style="color:#365314">900</span>
+    /// <span style="color:oklch(98.6% 0.031 120.757)">50</span>,
+    /// <span style="color:oklch(96.7% 0.067 122.328)">100</span>,
+    /// <span style="color:oklch(93.8% 0.127 124.321)">200</span>,
+    /// <span style="color:oklch(89.7% 0.196 126.665)">300</span>,
+    /// <span style="color:oklch(84.1% 0.238 128.85)">400</span>,
+    /// <span style="color:oklch(76.8% 0.233 130.85)">500</span>,
+    /// <span style="color:oklch(64.8% 0.2 131.684)">600</span>,
+    /// <span style="color:oklch(53.2% 0.157 131.589)">700</span>,
+    /// <span style="color:oklch(45.3% 0.124 130.933)">800</span>,
+    /// <span style="color:oklch(40.5% 0.101 131.063)">900</span>,
+    /// <span style="color:oklch(27.4% 0.072 132.109)">950</span>
     pub fn lime() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#F7FEE7").unwrap());
-        colors.insert(100, Srgb::from_str("#ECFCCB").unwrap());
-        colors.insert(200, Srgb::from_str("#D9F99D").unwrap());
-        colors.insert(300, Srgb::from_str("#BEF264").unwrap());
-        colors.insert(400, Srgb::from_str("#A3E635").unwrap());
-        colors.insert(500, Srgb::from_str("#84CC16").unwrap());
-        colors.insert(600, Srgb::from_str("#65A30D").unwrap());
-        colors.insert(700, Srgb::from_str("#4D7C0F").unwrap());
-        colors.insert(800, Srgb::from_str("#3F6212").unwrap());
-        colors.insert(900, Srgb::from_str("#365314").unwrap());
+        colors.insert(50, Oklab::from_lch(0.986, 0.031, 120.757, 1.0));
+        colors.insert(100, Oklab::from_lch(0.967, 0.067, 122.328, 1.0));
+        colors.insert(200, Oklab::from_lch(0.938, 0.127, 124.321, 1.0));
+        colors.insert(300, Oklab::from_lch(0.897, 0.196, 126.665, 1.0));
+        colors.insert(400, Oklab::from_lch(0.841, 0.238, 128.85, 1.0));
+        colors.insert(500, Oklab::from_lch(0.768, 0.233, 130.85, 1.0));
+        colors.insert(600, Oklab::from_lch(0.648, 0.2, 131.684, 1.0));
+        colors.insert(700, Oklab::from_lch(0.532, 0.157, 131.589, 1.0));
+        colors.insert(800, Oklab::from_lch(0.453, 0.124, 130.933, 1.0));
+        colors.insert(900, Oklab::from_lch(0.405, 0.101, 131.063, 1.0));
+        colors.insert(950, Oklab::from_lch(0.274, 0.072, 132.109, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## GREEN
-    /// <span style="color:#F0FDF4">50</span>,
-    /// <span style="color:#DCFCE7">100</span>,
-    /// <span style="color:#BBF7D0">200</span>,
-    /// <span style="color:#86EFAC">300</span>,
-    /// <span style="color:#4ADE80">400</span>,
-    /// <span style="color:#22C55E">500</span>,
-    /// <span style="color:#16A34A">600</span>,
-    /// <span style="color:#15803D">700</span>,
-    /// <span style="color:#166534">800</span>,
-    /// <span style="color:#14532D">900</span>
+    /// <span style="color:oklch(98.2% 0.018 155.826)">50</span>,
+    /// <span style="color:oklch(96.2% 0.044 156.743)">100</span>,
+    /// <span style="color:oklch(92.5% 0.084 155.995)">200</span>,
+    /// <span style="color:oklch(87.1% 0.15 154.449)">300</span>,
+    /// <span style="color:oklch(79.2% 0.209 151.711)">400</span>,
+    /// <span style="color:oklch(72.3% 0.219 149.579)">500</span>,
+    /// <span style="color:oklch(62.7% 0.194 149.214)">600</span>,
+    /// <span style="color:oklch(52.7% 0.154 150.069)">700</span>,
+    /// <span style="color:oklch(44.8% 0.119 151.328)">800</span>,
+    /// <span style="color:oklch(39.3% 0.095 152.535)">900</span>,
+    /// <span style="color:oklch(26.6% 0.065 152.934)">950</span>
     pub fn green() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#F0FDF4").unwrap());
-        colors.insert(100, Srgb::from_str("#DCFCE7").unwrap());
-        colors.insert(200, Srgb::from_str("#BBF7D0").unwrap());
-        colors.insert(300, Srgb::from_str("#86EFAC").unwrap());
-        colors.insert(400, Srgb::from_str("#4ADE80").unwrap());
-        colors.insert(500, Srgb::from_str("#22C55E").unwrap());
-        colors.insert(600, Srgb::from_str("#16A34A").unwrap());
-        colors.insert(700, Srgb::from_str("#15803D").unwrap());
-        colors.insert(800, Srgb::from_str("#166534").unwrap());
-        colors.insert(900, Srgb::from_str("#14532D").unwrap());
+        colors.insert(50, Oklab::from_lch(0.982, 0.018, 155.826, 1.0));
+        colors.insert(100, Oklab::from_lch(0.962, 0.044, 156.743, 1.0));
+        colors.insert(200, Oklab::from_lch(0.925, 0.084, 155.995, 1.0));
+        colors.insert(300, Oklab::from_lch(0.871, 0.15, 154.449, 1.0));
+        colors.insert(400, Oklab::from_lch(0.792, 0.209, 151.711, 1.0));
+        colors.insert(500, Oklab::from_lch(0.723, 0.219, 149.579, 1.0));
+        colors.insert(600, Oklab::from_lch(0.627, 0.194, 149.214, 1.0));
+        colors.insert(700, Oklab::from_lch(0.527, 0.154, 150.069, 1.0));
+        colors.insert(800, Oklab::from_lch(0.448, 0.119, 151.328, 1.0));
+        colors.insert(900, Oklab::from_lch(0.393, 0.095, 152.535, 1.0));
+        colors.insert(950, Oklab::from_lch(0.266, 0.065, 152.934, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## EMERALD
-    /// <span style="color:#ECFDF5">50</span>,
-    /// <span style="color:#D1FAE5">100</span>,
-    /// <span style="color:#A7F3D0">200</span>,
-    /// <span style="color:#6EE7B7">300</span>,
-    /// <span style="color:#34D399">400</span>,
-    /// <span style="color:#10B981">500</span>,
-    /// <span style="color:#059669">600</span>,
-    /// <span style="color:#047857">700</span>,
-    /// <span style="color:#065F46">800</span>,
-    /// <span style="color:#064E3B">900</span>
+    /// <span style="color:oklch(97.9% 0.021 166.113)">50</span>,
+    /// <span style="color:oklch(95% 0.052 163.051)">100</span>,
+    /// <span style="color:oklch(90.5% 0.093 164.15)">200</span>,
+    /// <span style="color:oklch(84.5% 0.143 164.978)">300</span>,
+    /// <span style="color:oklch(76.5% 0.177 163.223)">400</span>,
+    /// <span style="color:oklch(69.6% 0.17 162.48)">500</span>,
+    /// <span style="color:oklch(59.6% 0.145 163.225)">600</span>,
+    /// <span style="color:oklch(50.8% 0.118 165.612)">700</span>,
+    /// <span style="color:oklch(43.2% 0.095 166.913)">800</span>,
+    /// <span style="color:oklch(37.8% 0.077 168.94)">900</span>,
+    /// <span style="color:oklch(26.2% 0.051 172.552)">950</span>
     pub fn emerald() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#ECFDF5").unwrap());
-        colors.insert(100, Srgb::from_str("#D1FAE5").unwrap());
-        colors.insert(200, Srgb::from_str("#A7F3D0").unwrap());
-        colors.insert(300, Srgb::from_str("#6EE7B7").unwrap());
-        colors.insert(400, Srgb::from_str("#34D399").unwrap());
-        colors.insert(500, Srgb::from_str("#10B981").unwrap());
-        colors.insert(600, Srgb::from_str("#059669").unwrap());
-        colors.insert(700, Srgb::from_str("#047857").unwrap());
-        colors.insert(800, Srgb::from_str("#065F46").unwrap());
-        colors.insert(900, Srgb::from_str("#064E3B").unwrap());
+        colors.insert(50, Oklab::from_lch(0.979, 0.021, 166.113, 1.0));
+        colors.insert(100, Oklab::from_lch(0.95, 0.052, 163.051, 1.0));
+        colors.insert(200, Oklab::from_lch(0.905, 0.093, 164.15, 1.0));
+        colors.insert(300, Oklab::from_lch(0.845, 0.143, 164.978, 1.0));
+        colors.insert(400, Oklab::from_lch(0.765, 0.177, 163.223, 1.0));
+        colors.insert(500, Oklab::from_lch(0.696, 0.17, 162.48, 1.0));
+        colors.insert(600, Oklab::from_lch(0.596, 0.145, 163.225, 1.0));
+        colors.insert(700, Oklab::from_lch(0.508, 0.118, 165.612, 1.0));
+        colors.insert(800, Oklab::from_lch(0.432, 0.095, 166.913, 1.0));
+        colors.insert(900, Oklab::from_lch(0.378, 0.077, 168.94, 1.0));
+        colors.insert(950, Oklab::from_lch(0.262, 0.051, 172.552, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## TEAL
-    /// <span style="color:#F0FDFA">50</span>,
-    /// <span style="color:#CCFBF1">100</span>,
-    /// <span style="color:#99F6E4">200</span>,
-    /// <span style="color:#5EEAD4">300</span>,
-    /// <span style="color:#2DD4BF">400</span>,
-    /// <span style="color:#14B8A6">500</span>,
-    /// <span style="color:#0D9488">600</span>,
-    /// <span style="color:#0F766E">700</span>,
-    /// <span style="color:#115E59">800</span>,
-    /// <span style="color:#134E4A">900</span>
+    /// <span style="color:oklch(98.4% 0.014 180.72)">50</span>,
+    /// <span style="color:oklch(95.3% 0.051 180.801)">100</span>,
+    /// <span style="color:oklch(91% 0.096 180.426)">200</span>,
+    /// <span style="color:oklch(85.5% 0.138 181.071)">300</span>,
+    /// <span style="color:oklch(77.7% 0.152 181.912)">400</span>,
+    /// <span style="color:oklch(70.4% 0.14 182.503)">500</span>,
+    /// <span style="color:oklch(60% 0.118 184.704)">600</span>,
+    /// <span style="color:oklch(51.1% 0.096 186.391)">700</span>,
+    /// <span style="color:oklch(43.7% 0.078 188.216)">800</span>,
+    /// <span style="color:oklch(38.6% 0.063 188.416)">900</span>,
+    /// <span style="color:oklch(27.7% 0.046 192.524)">950</span>
     pub fn teal() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#F0FDFA").unwrap());
-        colors.insert(100, Srgb::from_str("#CCFBF1").unwrap());
-        colors.insert(200, Srgb::from_str("#99F6E4").unwrap());
-        colors.insert(300, Srgb::from_str("#5EEAD4").unwrap());
-        colors.insert(400, Srgb::from_str("#2DD4BF").unwrap());
-        colors.insert(500, Srgb::from_str("#14B8A6").unwrap());
-        colors.insert(600, Srgb::from_str("#0D9488").unwrap());
-        colors.insert(700, Srgb::from_str("#0F766E").unwrap());
-        colors.insert(800, Srgb::from_str("#115E59").unwrap());
-        colors.insert(900, Srgb::from_str("#134E4A").unwrap());
+        colors.insert(50, Oklab::from_lch(0.984, 0.014, 180.72, 1.0));
+        colors.insert(100, Oklab::from_lch(0.953, 0.051, 180.801, 1.0));
+        colors.insert(200, Oklab::from_lch(0.91, 0.096, 180.426, 1.0));
+        colors.insert(300, Oklab::from_lch(0.855, 0.138, 181.071, 1.0));
+        colors.insert(400, Oklab::from_lch(0.777, 0.152, 181.912, 1.0));
+        colors.insert(500, Oklab::from_lch(0.704, 0.14, 182.503, 1.0));
+        colors.insert(600, Oklab::from_lch(0.6, 0.118, 184.704, 1.0));
+        colors.insert(700, Oklab::from_lch(0.511, 0.096, 186.391, 1.0));
+        colors.insert(800, Oklab::from_lch(0.437, 0.078, 188.216, 1.0));
+        colors.insert(900, Oklab::from_lch(0.386, 0.063, 188.416, 1.0));
+        colors.insert(950, Oklab::from_lch(0.277, 0.046, 192.524, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## CYAN
-    /// <span style="color:#ECFEFF">50</span>,
-    /// <span style="color:#CFFAFE">100</span>,
-    /// <span style="color:#A5F3FC">200</span>,
-    /// <span style="color:#67E8F9">300</span>,
-    /// <span style="color:#22D3EE">400</span>,
-    /// <span style="color:#06B6D4">500</span>,
-    /// <span style="color:#0891B2">600</span>,
-    /// <span style="color:#0E7490">700</span>,
-    /// <span style="color:#155E75">800</span>,
-    /// <span style="color:#164E63">900</span>
+    /// <span style="color:oklch(98.4% 0.019 200.873)">50</span>,
+    /// <span style="color:oklch(95.6% 0.045 203.388)">100</span>,
+    /// <span style="color:oklch(91.7% 0.08 205.041)">200</span>,
+    /// <span style="color:oklch(86.5% 0.127 207.078)">300</span>,
+    /// <span style="color:oklch(78.9% 0.154 211.53)">400</span>,
+    /// <span style="color:oklch(71.5% 0.143 215.221)">500</span>,
+    /// <span style="color:oklch(60.9% 0.126 221.723)">600</span>,
+    /// <span style="color:oklch(52% 0.105 223.128)">700</span>,
+    /// <span style="color:oklch(45% 0.085 224.283)">800</span>,
+    /// <span style="color:oklch(39.8% 0.07 227.392)">900</span>,
+    /// <span style="color:oklch(30.2% 0.056 229.695)">950</span>
     pub fn cyan() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#ECFEFF").unwrap());
-        colors.insert(100, Srgb::from_str("#CFFAFE").unwrap());
-        colors.insert(200, Srgb::from_str("#A5F3FC").unwrap());
-        colors.insert(300, Srgb::from_str("#67E8F9").unwrap());
-        colors.insert(400, Srgb::from_str("#22D3EE").unwrap());
-        colors.insert(500, Srgb::from_str("#06B6D4").unwrap());
-        colors.insert(600, Srgb::from_str("#0891B2").unwrap());
-        colors.insert(700, Srgb::from_str("#0E7490").unwrap());
-        colors.insert(800, Srgb::from_str("#155E75").unwrap());
-        colors.insert(900, Srgb::from_str("#164E63").unwrap());
+        colors.insert(50, Oklab::from_lch(0.984, 0.019, 200.873, 1.0));
+        colors.insert(100, Oklab::from_lch(0.956, 0.045, 203.388, 1.0));
+        colors.insert(200, Oklab::from_lch(0.917, 0.08, 205.041, 1.0));
+        colors.insert(300, Oklab::from_lch(0.865, 0.127, 207.078, 1.0));
+        colors.insert(400, Oklab::from_lch(0.789, 0.154, 211.53, 1.0));
+        colors.insert(500, Oklab::from_lch(0.715, 0.143, 215.221, 1.0));
+        colors.insert(600, Oklab::from_lch(0.609, 0.126, 221.723, 1.0));
+        colors.insert(700, Oklab::from_lch(0.52, 0.105, 223.128, 1.0));
+        colors.insert(800, Oklab::from_lch(0.45, 0.085, 224.283, 1.0));
+        colors.insert(900, Oklab::from_lch(0.398, 0.07, 227.392, 1.0));
+        colors.insert(950, Oklab::from_lch(0.302, 0.056, 229.695, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## SKY
-    /// <span style="color:#F0F9FF">50</span>,
-    /// <span style="color:#E0F2FE">100</span>,
-    /// <span style="color:#BAE6FD">200</span>,
-    /// <span style="color:#7DD3FC">300</span>,
-    /// <span style="color:#38BDF8">400</span>,
-    /// <span style="color:#0EA5E9">500</span>,
-    /// <span style="color:#0284C7">600</span>,
-    /// <span style="color:#0369A1">700</span>,
-    /// <span style="color:#075985">800</span>,
-    /// <span style="color:#0C4A6E">900</span>
+    /// <span style="color:oklch(97.7% 0.013 236.62)">50</span>,
+    /// <span style="color:oklch(95.1% 0.026 236.824)">100</span>,
+    /// <span style="color:oklch(90.1% 0.058 230.902)">200</span>,
+    /// <span style="color:oklch(82.8% 0.111 230.318)">300</span>,
+    /// <span style="color:oklch(74.6% 0.16 232.661)">400</span>,
+    /// <span style="color:oklch(68.5% 0.169 237.323)">500</span>,
+    /// <span style="color:oklch(58.8% 0.158 241.966)">600</span>,
+    /// <span style="color:oklch(50% 0.134 242.749)">700</span>,
+    /// <span style="color:oklch(44.3% 0.11 240.79)">800</span>,
+    /// <span style="color:oklch(39.1% 0.09 240.876)">900</span>,
+    /// <span style="color:oklch(29.3% 0.066 243.157)">950</span>
     pub fn sky() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#F0F9FF").unwrap());
-        colors.insert(100, Srgb::from_str("#E0F2FE").unwrap());
-        colors.insert(200, Srgb::from_str("#BAE6FD").unwrap());
-        colors.insert(300, Srgb::from_str("#7DD3FC").unwrap());
-        colors.insert(400, Srgb::from_str("#38BDF8").unwrap());
-        colors.insert(500, Srgb::from_str("#0EA5E9").unwrap());
-        colors.insert(600, Srgb::from_str("#0284C7").unwrap());
-        colors.insert(700, Srgb::from_str("#0369A1").unwrap());
-        colors.insert(800, Srgb::from_str("#075985").unwrap());
-        colors.insert(900, Srgb::from_str("#0C4A6E").unwrap());
+        colors.insert(50, Oklab::from_lch(0.977, 0.013, 236.62, 1.0));
+        colors.insert(100, Oklab::from_lch(0.951, 0.026, 236.824, 1.0));
+        colors.insert(200, Oklab::from_lch(0.901, 0.058, 230.902, 1.0));
+        colors.insert(300, Oklab::from_lch(0.828, 0.111, 230.318, 1.0));
+        colors.insert(400, Oklab::from_lch(0.746, 0.16, 232.661, 1.0));
+        colors.insert(500, Oklab::from_lch(0.685, 0.169, 237.323, 1.0));
+        colors.insert(600, Oklab::from_lch(0.588, 0.158, 241.966, 1.0));
+        colors.insert(700, Oklab::from_lch(0.5, 0.134, 242.749, 1.0));
+        colors.insert(800, Oklab::from_lch(0.443, 0.11, 240.79, 1.0));
+        colors.insert(900, Oklab::from_lch(0.391, 0.09, 240.876, 1.0));
+        colors.insert(950, Oklab::from_lch(0.293, 0.066, 243.157, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## BLUE
-    /// <span style="color:#EFF6FF">50</span>,
-    /// <span style="color:#DBEAFE">100</span>,
-    /// <span style="color:#BFDBFE">200</span>,
-    /// <span style="color:#93C5FD">300</span>,
-    /// <span style="color:#60A5FA">400</span>,
-    /// <span style="color:#3B82F6">500</span>,
-    /// <span style="color:#2563EB">600</span>,
-    /// <span style="color:#1D4ED8">700</span>,
-    /// <span style="color:#1E40AF">800</span>,
-    /// <span style="color:#1E3A8A">900</span>
+    /// <span style="color:oklch(97% 0.014 254.604)">50</span>,
+    /// <span style="color:oklch(93.2% 0.032 255.585)">100</span>,
+    /// <span style="color:oklch(88.2% 0.059 254.128)">200</span>,
+    /// <span style="color:oklch(80.9% 0.105 251.813)">300</span>,
+    /// <span style="color:oklch(70.7% 0.165 254.624)">400</span>,
+    /// <span style="color:oklch(62.3% 0.214 259.815)">500</span>,
+    /// <span style="color:oklch(54.6% 0.245 262.881)">600</span>,
+    /// <span style="color:oklch(48.8% 0.243 264.376)">700</span>,
+    /// <span style="color:oklch(42.4% 0.199 265.638)">800</span>,
+    /// <span style="color:oklch(37.9% 0.146 265.522)">900</span>,
+    /// <span style="color:oklch(28.2% 0.091 267.935)">950</span>
     pub fn blue() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#EFF6FF").unwrap());
-        colors.insert(100, Srgb::from_str("#DBEAFE").unwrap());
-        colors.insert(200, Srgb::from_str("#BFDBFE").unwrap());
-        colors.insert(300, Srgb::from_str("#93C5FD").unwrap());
-        colors.insert(400, Srgb::from_str("#60A5FA").unwrap());
-        colors.insert(500, Srgb::from_str("#3B82F6").unwrap());
-        colors.insert(600, Srgb::from_str("#2563EB").unwrap());
-        colors.insert(700, Srgb::from_str("#1D4ED8").unwrap());
-        colors.insert(800, Srgb::from_str("#1E40AF").unwrap());
-        colors.insert(900, Srgb::from_str("#1E3A8A").unwrap());
+        colors.insert(50, Oklab::from_lch(0.97, 0.014, 254.604, 1.0));
+        colors.insert(100, Oklab::from_lch(0.932, 0.032, 255.585, 1.0));
+        colors.insert(200, Oklab::from_lch(0.882, 0.059, 254.128, 1.0));
+        colors.insert(300, Oklab::from_lch(0.809, 0.105, 251.813, 1.0));
+        colors.insert(400, Oklab::from_lch(0.707, 0.165, 254.624, 1.0));
+        colors.insert(500, Oklab::from_lch(0.623, 0.214, 259.815, 1.0));
+        colors.insert(600, Oklab::from_lch(0.546, 0.245, 262.881, 1.0));
+        colors.insert(700, Oklab::from_lch(0.488, 0.243, 264.376, 1.0));
+        colors.insert(800, Oklab::from_lch(0.424, 0.199, 265.638, 1.0));
+        colors.insert(900, Oklab::from_lch(0.379, 0.146, 265.522, 1.0));
+        colors.insert(950, Oklab::from_lch(0.282, 0.091, 267.935, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## INDIGO
-    /// <span style="color:#EEF2FF">50</span>,
-    /// <span style="color:#E0E7FF">100</span>,
-    /// <span style="color:#C7D2FE">200</span>,
-    /// <span style="color:#A5B4FC">300</span>,
-    /// <span style="color:#818CF8">400</span>,
-    /// <span style="color:#6366F1">500</span>,
-    /// <span style="color:#4F46E5">600</span>,
-    /// <span style="color:#4338CA">700</span>,
-    /// <span style="color:#3730A3">800</span>,
-    /// <span style="color:#312E81">900</span>
+    /// <span style="color:oklch(96.2% 0.018 272.314)">50</span>,
+    /// <span style="color:oklch(93% 0.034 272.788)">100</span>,
+    /// <span style="color:oklch(87% 0.065 274.039)">200</span>,
+    /// <span style="color:oklch(78.5% 0.115 274.713)">300</span>,
+    /// <span style="color:oklch(67.3% 0.182 276.935)">400</span>,
+    /// <span style="color:oklch(58.5% 0.233 277.117)">500</span>,
+    /// <span style="color:oklch(51.1% 0.262 276.966)">600</span>,
+    /// <span style="color:oklch(45.7% 0.24 277.023)">700</span>,
+    /// <span style="color:oklch(39.8% 0.195 277.366)">800</span>,
+    /// <span style="color:oklch(35.9% 0.144 278.697)">900</span>,
+    /// <span style="color:oklch(25.7% 0.09 281.288)">950</span>
     pub fn indigo() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#EEF2FF").unwrap());
-        colors.insert(100, Srgb::from_str("#E0E7FF").unwrap());
-        colors.insert(200, Srgb::from_str("#C7D2FE").unwrap());
-        colors.insert(300, Srgb::from_str("#A5B4FC").unwrap());
-        colors.insert(400, Srgb::from_str("#818CF8").unwrap());
-        colors.insert(500, Srgb::from_str("#6366F1").unwrap());
-        colors.insert(600, Srgb::from_str("#4F46E5").unwrap());
-        colors.insert(700, Srgb::from_str("#4338CA").unwrap());
-        colors.insert(800, Srgb::from_str("#3730A3").unwrap());
-        colors.insert(900, Srgb::from_str("#312E81").unwrap());
+        colors.insert(50, Oklab::from_lch(0.962, 0.018, 272.314, 1.0));
+        colors.insert(100, Oklab::from_lch(0.93, 0.034, 272.788, 1.0));
+        colors.insert(200, Oklab::from_lch(0.87, 0.065, 274.039, 1.0));
+        colors.insert(300, Oklab::from_lch(0.785, 0.115, 274.713, 1.0));
+        colors.insert(400, Oklab::from_lch(0.673, 0.182, 276.935, 1.0));
+        colors.insert(500, Oklab::from_lch(0.585, 0.233, 277.117, 1.0));
+        colors.insert(600, Oklab::from_lch(0.511, 0.262, 276.966, 1.0));
+        colors.insert(700, Oklab::from_lch(0.457, 0.24, 277.023, 1.0));
+        colors.insert(800, Oklab::from_lch(0.398, 0.195, 277.366, 1.0));
+        colors.insert(900, Oklab::from_lch(0.359, 0.144, 278.697, 1.0));
+        colors.insert(950, Oklab::from_lch(0.257, 0.09, 281.288, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## VIOLET
-    /// <span style="color:#F5F3FF">50</span>,
-    /// <span style="color:#EDE9FE">100</span>,
-    /// <span style="color:#DDD6FE">200</span>,
-    /// <span style="color:#C4B5FD">300</span>,
-    /// <span style="color:#A78BFA">400</span>,
-    /// <span style="color:#8B5CF6">500</span>,
-    /// <span style="color:#7C3AED">600</span>,
-    /// <span style="color:#6D28D9">700</span>,
-    /// <span style="color:#5B21B6">800</span>,
-    /// <span style="color:#4C1D95">900</span>
+    /// <span style="color:oklch(96.9% 0.016 293.756)">50</span>,
+    /// <span style="color:oklch(94.3% 0.029 294.588)">100</span>,
+    /// <span style="color:oklch(89.4% 0.057 293.283)">200</span>,
+    /// <span style="color:oklch(81.1% 0.111 293.571)">300</span>,
+    /// <span style="color:oklch(70.2% 0.183 293.541)">400</span>,
+    /// <span style="color:oklch(60.6% 0.25 292.717)">500</span>,
+    /// <span style="color:oklch(54.1% 0.281 293.009)">600</span>,
+    /// <span style="color:oklch(49.1% 0.27 292.581)">700</span>,
+    /// <span style="color:oklch(43.2% 0.232 292.759)">800</span>,
+    /// <span style="color:oklch(38% 0.189 293.745)">900</span>,
+    /// <span style="color:oklch(28.3% 0.141 291.089)">950</span>
     pub fn violet() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#F5F3FF").unwrap());
-        colors.insert(100, Srgb::from_str("#EDE9FE").unwrap());
-        colors.insert(200, Srgb::from_str("#DDD6FE").unwrap());
-        colors.insert(300, Srgb::from_str("#C4B5FD").unwrap());
-        colors.insert(400, Srgb::from_str("#A78BFA").unwrap());
-        colors.insert(500, Srgb::from_str("#8B5CF6").unwrap());
-        colors.insert(600, Srgb::from_str("#7C3AED").unwrap());
-        colors.insert(700, Srgb::from_str("#6D28D9").unwrap());
-        colors.insert(800, Srgb::from_str("#5B21B6").unwrap());
-        colors.insert(900, Srgb::from_str("#4C1D95").unwrap());
+        colors.insert(50, Oklab::from_lch(0.969, 0.016, 293.756, 1.0));
+        colors.insert(100, Oklab::from_lch(0.943, 0.029, 294.588, 1.0));
+        colors.insert(200, Oklab::from_lch(0.894, 0.057, 293.283, 1.0));
+        colors.insert(300, Oklab::from_lch(0.811, 0.111, 293.571, 1.0));
+        colors.insert(400, Oklab::from_lch(0.702, 0.183, 293.541, 1.0));
+        colors.insert(500, Oklab::from_lch(0.606, 0.25, 292.717, 1.0));
+        colors.insert(600, Oklab::from_lch(0.541, 0.281, 293.009, 1.0));
+        colors.insert(700, Oklab::from_lch(0.491, 0.27, 292.581, 1.0));
+        colors.insert(800, Oklab::from_lch(0.432, 0.232, 292.759, 1.0));
+        colors.insert(900, Oklab::from_lch(0.38, 0.189, 293.745, 1.0));
+        colors.insert(950, Oklab::from_lch(0.283, 0.141, 291.089, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## PURPLE
-    /// <span style="color:#FAF5FF">50</span>,
-    /// <span style="color:#F3E8FF">100</span>,
-    /// <span style="color:#E9D5FF">200</span>,
-    /// <span style="color:#D8B4FE">300</span>,
-    /// <span style="color:#C084FC">400</span>,
-    /// <span style="color:#A855F7">500</span>,
-    /// <span style="color:#9333EA">600</span>,
-    /// <span style="color:#7E22CE">700</span>,
-    /// <span style="color:#6B21A8">800</span>,
-    /// <span style="color:#581C87">900</span>
+    /// <span style="color:oklch(97.7% 0.014 308.299)">50</span>,
+    /// <span style="color:oklch(94.6% 0.033 307.174)">100</span>,
+    /// <span style="color:oklch(90.2% 0.063 306.703)">200</span>,
+    /// <span style="color:oklch(82.7% 0.119 306.383)">300</span>,
+    /// <span style="color:oklch(71.4% 0.203 305.504)">400</span>,
+    /// <span style="color:oklch(62.7% 0.265 303.9)">500</span>,
+    /// <span style="color:oklch(55.8% 0.288 302.321)">600</span>,
+    /// <span style="color:oklch(49.6% 0.265 301.924)">700</span>,
+    /// <span style="color:oklch(43.8% 0.218 303.724)">800</span>,
+    /// <span style="color:oklch(38.1% 0.176 304.987)">900</span>,
+    /// <span style="color:oklch(29.1% 0.149 302.717)">950</span>
     pub fn purple() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FAF5FF").unwrap());
-        colors.insert(100, Srgb::from_str("#F3E8FF").unwrap());
-        colors.insert(200, Srgb::from_str("#E9D5FF").unwrap());
-        colors.insert(300, Srgb::from_str("#D8B4FE").unwrap());
-        colors.insert(400, Srgb::from_str("#C084FC").unwrap());
-        colors.insert(500, Srgb::from_str("#A855F7").unwrap());
-        colors.insert(600, Srgb::from_str("#9333EA").unwrap());
-        colors.insert(700, Srgb::from_str("#7E22CE").unwrap());
-        colors.insert(800, Srgb::from_str("#6B21A8").unwrap());
-        colors.insert(900, Srgb::from_str("#581C87").unwrap());
+        colors.insert(50, Oklab::from_lch(0.977, 0.014, 308.299, 1.0));
+        colors.insert(100, Oklab::from_lch(0.946, 0.033, 307.174, 1.0));
+        colors.insert(200, Oklab::from_lch(0.902, 0.063, 306.703, 1.0));
+        colors.insert(300, Oklab::from_lch(0.827, 0.119, 306.383, 1.0));
+        colors.insert(400, Oklab::from_lch(0.714, 0.203, 305.504, 1.0));
+        colors.insert(500, Oklab::from_lch(0.627, 0.265, 303.9, 1.0));
+        colors.insert(600, Oklab::from_lch(0.558, 0.288, 302.321, 1.0));
+        colors.insert(700, Oklab::from_lch(0.496, 0.265, 301.924, 1.0));
+        colors.insert(800, Oklab::from_lch(0.438, 0.218, 303.724, 1.0));
+        colors.insert(900, Oklab::from_lch(0.381, 0.176, 304.987, 1.0));
+        colors.insert(950, Oklab::from_lch(0.291, 0.149, 302.717, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## FUCHSIA
-    /// <span style="color:#FDF4FF">50</span>,
-    /// <span style="color:#FAE8FF">100</span>,
-    /// <span style="color:#F5D0FE">200</span>,
-    /// <span style="color:#F0ABFC">300</span>,
-    /// <span style="color:#E879F9">400</span>,
-    /// <span style="color:#D946EF">500</span>,
-    /// <span style="color:#C026D3">600</span>,
-    /// <span style="color:#A21CAF">700</span>,
-    /// <span style="color:#86198F">800</span>,
-    /// <span style="color:#701A75">900</span>
+    /// <span style="color:oklch(97.7% 0.017 320.058)">50</span>,
+    /// <span style="color:oklch(95.2% 0.037 318.852)">100</span>,
+    /// <span style="color:oklch(90.3% 0.076 319.62)">200</span>,
+    /// <span style="color:oklch(83.3% 0.145 321.434)">300</span>,
+    /// <span style="color:oklch(74% 0.238 322.16)">400</span>,
+    /// <span style="color:oklch(66.7% 0.295 322.15)">500</span>,
+    /// <span style="color:oklch(59.1% 0.293 322.896)">600</span>,
+    /// <span style="color:oklch(51.8% 0.253 323.949)">700</span>,
+    /// <span style="color:oklch(45.2% 0.211 324.591)">800</span>,
+    /// <span style="color:oklch(40.1% 0.17 325.612)">900</span>,
+    /// <span style="color:oklch(29.3% 0.136 325.661)">950</span>
     pub fn fuchsia() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FDF4FF").unwrap());
-        colors.insert(100, Srgb::from_str("#FAE8FF").unwrap());
-        colors.insert(200, Srgb::from_str("#F5D0FE").unwrap());
-        colors.insert(300, Srgb::from_str("#F0ABFC").unwrap());
-        colors.insert(400, Srgb::from_str("#E879F9").unwrap());
-        colors.insert(500, Srgb::from_str("#D946EF").unwrap());
-        colors.insert(600, Srgb::from_str("#C026D3").unwrap());
-        colors.insert(700, Srgb::from_str("#A21CAF").unwrap());
-        colors.insert(800, Srgb::from_str("#86198F").unwrap());
-        colors.insert(900, Srgb::from_str("#701A75").unwrap());
+        colors.insert(50, Oklab::from_lch(0.977, 0.017, 320.058, 1.0));
+        colors.insert(100, Oklab::from_lch(0.952, 0.037, 318.852, 1.0));
+        colors.insert(200, Oklab::from_lch(0.903, 0.076, 319.62, 1.0));
+        colors.insert(300, Oklab::from_lch(0.833, 0.145, 321.434, 1.0));
+        colors.insert(400, Oklab::from_lch(0.74, 0.238, 322.16, 1.0));
+        colors.insert(500, Oklab::from_lch(0.667, 0.295, 322.15, 1.0));
+        colors.insert(600, Oklab::from_lch(0.591, 0.293, 322.896, 1.0));
+        colors.insert(700, Oklab::from_lch(0.518, 0.253, 323.949, 1.0));
+        colors.insert(800, Oklab::from_lch(0.452, 0.211, 324.591, 1.0));
+        colors.insert(900, Oklab::from_lch(0.401, 0.17, 325.612, 1.0));
+        colors.insert(950, Oklab::from_lch(0.293, 0.136, 325.661, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## PINK
-    /// <span style="color:#FDF2F8">50</span>,
-    /// <span style="color:#FCE7F3">100</span>,
-    /// <span style="color:#FBCFE8">200</span>,
-    /// <span style="color:#F9A8D4">300</span>,
-    /// <span style="color:#F472B6">400</span>,
-    /// <span style="color:#EC4899">500</span>,
-    /// <span style="color:#DB2777">600</span>,
-    /// <span style="color:#BE185D">700</span>,
-    /// <span style="color:#9D174D">800</span>,
-    /// <span style="color:#831843">900</span>
+    /// <span style="color:oklch(97.1% 0.014 343.198)">50</span>,
+    /// <span style="color:oklch(94.8% 0.028 342.258)">100</span>,
+    /// <span style="color:oklch(89.9% 0.061 343.231)">200</span>,
+    /// <span style="color:oklch(82.3% 0.12 346.018)">300</span>,
+    /// <span style="color:oklch(71.8% 0.202 349.761)">400</span>,
+    /// <span style="color:oklch(65.6% 0.241 354.308)">500</span>,
+    /// <span style="color:oklch(59.2% 0.249 0.584)">600</span>,
+    /// <span style="color:oklch(52.5% 0.223 3.958)">700</span>,
+    /// <span style="color:oklch(45.9% 0.187 3.815)">800</span>,
+    /// <span style="color:oklch(40.8% 0.153 2.432)">900</span>,
+    /// <span style="color:oklch(28.4% 0.109 3.907)">950</span>
     pub fn pink() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FDF2F8").unwrap());
-        colors.insert(100, Srgb::from_str("#FCE7F3").unwrap());
-        colors.insert(200, Srgb::from_str("#FBCFE8").unwrap());
-        colors.insert(300, Srgb::from_str("#F9A8D4").unwrap());
-        colors.insert(400, Srgb::from_str("#F472B6").unwrap());
-        colors.insert(500, Srgb::from_str("#EC4899").unwrap());
-        colors.insert(600, Srgb::from_str("#DB2777").unwrap());
-        colors.insert(700, Srgb::from_str("#BE185D").unwrap());
-        colors.insert(800, Srgb::from_str("#9D174D").unwrap());
-        colors.insert(900, Srgb::from_str("#831843").unwrap());
+        colors.insert(50, Oklab::from_lch(0.971, 0.014, 343.198, 1.0));
+        colors.insert(100, Oklab::from_lch(0.948, 0.028, 342.258, 1.0));
+        colors.insert(200, Oklab::from_lch(0.899, 0.061, 343.231, 1.0));
+        colors.insert(300, Oklab::from_lch(0.823, 0.12, 346.018, 1.0));
+        colors.insert(400, Oklab::from_lch(0.718, 0.202, 349.761, 1.0));
+        colors.insert(500, Oklab::from_lch(0.656, 0.241, 354.308, 1.0));
+        colors.insert(600, Oklab::from_lch(0.592, 0.249, 0.584, 1.0));
+        colors.insert(700, Oklab::from_lch(0.525, 0.223, 3.958, 1.0));
+        colors.insert(800, Oklab::from_lch(0.459, 0.187, 3.815, 1.0));
+        colors.insert(900, Oklab::from_lch(0.408, 0.153, 2.432, 1.0));
+        colors.insert(950, Oklab::from_lch(0.284, 0.109, 3.907, 1.0));
         Self { gradient: true, key_points: colors }
     }
     /// ## ROSE
-    /// <span style="color:#FFF1F2">50</span>,
-    /// <span style="color:#FFE4E6">100</span>,
-    /// <span style="color:#FECDD3">200</span>,
-    /// <span style="color:#FDA4AF">300</span>,
-    /// <span style="color:#FB7185">400</span>,
-    /// <span style="color:#F43F5E">500</span>,
-    /// <span style="color:#E11D48">600</span>,
-    /// <span style="color:#BE123C">700</span>,
-    /// <span style="color:#9F1239">800</span>,
-    /// <span style="color:#881337">900</span>
+    /// <span style="color:oklch(96.9% 0.015 12.422)">50</span>,
+    /// <span style="color:oklch(94.1% 0.03 12.58)">100</span>,
+    /// <span style="color:oklch(89.2% 0.058 10.001)">200</span>,
+    /// <span style="color:oklch(81% 0.117 11.638)">300</span>,
+    /// <span style="color:oklch(71.2% 0.194 13.428)">400</span>,
+    /// <span style="color:oklch(64.5% 0.246 16.439)">500</span>,
+    /// <span style="color:oklch(58.6% 0.253 17.585)">600</span>,
+    /// <span style="color:oklch(51.4% 0.222 16.935)">700</span>,
+    /// <span style="color:oklch(45.5% 0.188 13.697)">800</span>,
+    /// <span style="color:oklch(41% 0.159 10.272)">900</span>,
+    /// <span style="color:oklch(27.1% 0.105 12.094)">950</span>
     pub fn rose() -> Self {
         let mut colors = BTreeMap::default();
-        colors.insert(50, Srgb::from_str("#FFF1F2").unwrap());
-        colors.insert(100, Srgb::from_str("#FFE4E6").unwrap());
-        colors.insert(200, Srgb::from_str("#FECDD3").unwrap());
-        colors.insert(300, Srgb::from_str("#FDA4AF").unwrap());
-        colors.insert(400, Srgb::from_str("#FB7185").unwrap());
-        colors.insert(500, Srgb::from_str("#F43F5E").unwrap());
-        colors.insert(600, Srgb::from_str("#E11D48").unwrap());
-        colors.insert(700, Srgb::from_str("#BE123C").unwrap());
-        colors.insert(800, Srgb::from_str("#9F1239").unwrap());
-        colors.insert(900, Srgb::from_str("#881337").unwrap());
+        colors.insert(50, Oklab::from_lch(0.969, 0.015, 12.422, 1.0));
+        colors.insert(100, Oklab::from_lch(0.941, 0.03, 12.58, 1.0));
+        colors.insert(200, Oklab::from_lch(0.892, 0.058, 10.001, 1.0));
+        colors.insert(300, Oklab::from_lch(0.81, 0.117, 11.638, 1.0));
+        colors.insert(400, Oklab::from_lch(0.712, 0.194, 13.428, 1.0));
+        colors.insert(500, Oklab::from_lch(0.645, 0.246, 16.439, 1.0));
+        colors.insert(600, Oklab::from_lch(0.586, 0.253, 17.585, 1.0));
+        colors.insert(700, Oklab::from_lch(0.514, 0.222, 16.935, 1.0));
+        colors.insert(800, Oklab::from_lch(0.455, 0.188, 13.697, 1.0));
+        colors.insert(900, Oklab::from_lch(0.41, 0.159, 10.272, 1.0));
+        colors.insert(950, Oklab::from_lch(0.271, 0.105, 12.094, 1.0));
         Self { gradient: true, key_points: colors }
     }
 }
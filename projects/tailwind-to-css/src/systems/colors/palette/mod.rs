@@ -1,5 +1,8 @@
 use super::*;
 mod builtin;
+mod ramp;
+
+pub use self::ramp::PaletteRamp;
 
 /// In general, it is a look-up table.
 ///
@@ -8,22 +11,89 @@ mod builtin;
 pub struct Palette {
     /// Allow gradients?
     gradient: bool,
-    /// min-width
-    /// unit: px
-    key_points: BTreeMap<u32, Srgb>,
+    /// Colors by weight, kept in OKLab so that the OKLCH values are not clamped to sRGB
+    key_points: BTreeMap<u32, Oklab>,
 }
 
 impl Palette {
+    /// A palette from weighted colors, e.g. `500 => #8B5CF6`
+    pub fn new<C: Into<Oklab>>(key_points: BTreeMap<u32, C>) -> Self {
+        Self { gradient: true, key_points: key_points.into_iter().map(|(k, v)| (k, v.into())).collect() }
+    }
+    /// Add the weighted colors, existing weights are overwritten
+    pub fn extend(&mut self, other: Palette) {
+        self.key_points.extend(other.key_points)
+    }
+    /// Get the color of a weight, weights between the key points are mixed in OKLab
     ///
-    pub fn get_color(&self, weight: u32) -> Result<Srgb> {
-        match self.key_points.get(&weight) {
-            Some(s) => Ok(*s),
-            None if self.gradient => {
-                syntax_error!("TODO")
-            },
-            None => {
-                syntax_error!("TODO")
-            },
+    /// White and black are the implicit key points at `0` and `1000`, e.g. `red-450` or `blue-925`.
+    pub fn get_color(&self, weight: u32) -> Result<Oklab> {
+        if let Some(s) = self.key_points.get(&weight) {
+            return Ok(*s);
+        }
+        if !self.gradient {
+            return syntax_error!("no such weight {} in palette", weight);
+        }
+        if weight > 1000 {
+            return syntax_error!("color weight must be in 0..=1000, found {}", weight);
+        }
+        let white = Oklab { l: 1.0, a: 0.0, b: 0.0, alpha: 1.0 };
+        let black = Oklab { l: 0.0, a: 0.0, b: 0.0, alpha: 1.0 };
+        let (lo, lo_color) = self.key_points.range(..weight).next_back().map_or((0, white), |(k, v)| (*k, *v));
+        let (hi, hi_color) = self.key_points.range(weight..).next().map_or((1000, black), |(k, v)| (*k, *v));
+        let t = (weight - lo) as f32 / (hi - lo) as f32;
+        Ok(lo_color.mix(&hi_color, t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(c: Oklab) -> String {
+        let c = Srgb::from(c);
+        format!("#{:02X}{:02X}{:02X}", (c.red * 255.0).round() as u8, (c.green * 255.0).round() as u8, (c.blue * 255.0).round() as u8)
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let red = Palette::red();
+        assert_eq!(hex(red.get_color(500).unwrap()), "#FB2C36");
+        // between `oklch(70.4% 0.191 22.216)` and `oklch(63.7% 0.237 25.331)`
+        assert_eq!(hex(red.get_color(450).unwrap()), "#FE4C50");
+        // towards the implicit black
+        assert_eq!(hex(red.get_color(1000).unwrap()), "#000000");
+        assert!(red.get_color(1001).is_err());
+        let fixed = Palette { gradient: false, ..Palette::red() };
+        assert!(fixed.get_color(450).is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        let brand = Palette::generate(Srgb::from_str("#9A66FF").unwrap(), &PaletteRamp::default());
+        assert_eq!(brand.key_points.len(), 11);
+        assert_eq!(hex(brand.get_color(500).unwrap()), "#9A66FF");
+        let lightness: Vec<f32> = brand.key_points.values().map(|c| c.l).collect();
+        assert!(lightness.windows(2).all(|w| w[0] > w[1]));
+        assert_eq!(hex(brand.get_color(50).unwrap()), "#F6F4FF");
+        assert_eq!(hex(brand.get_color(900).unwrap()), "#53219B");
+        let mut palettes = PaletteSystem::default();
+        palettes.register_generated("brand".to_string(), "#9A66FF").unwrap();
+        assert_eq!(hex(palettes.try_get_color("brand", 100).unwrap()), "#EBE6FE");
+        assert!(palettes.register_generated("brand".to_string(), "nope").is_err());
+    }
+
+    #[test]
+    fn test_generate_extremes() {
+        for color in ["#0F172A", "#FAFAFA", "#000000", "#FFFFFF"] {
+            let palette = Palette::generate(Srgb::from_str(color).unwrap(), &PaletteRamp::default());
+            assert_eq!(hex(palette.get_color(500).unwrap()), color);
+            let lightness: Vec<f32> = palette.key_points.values().map(|c| c.l).collect();
+            assert!(lightness.windows(2).all(|w| w[0] >= w[1]), "{}: {:?}", color, lightness);
         }
+        let slate = Palette::generate(Srgb::from_str("#0F172A").unwrap(), &PaletteRamp::default());
+        assert!(slate.get_color(950).unwrap().l < slate.get_color(900).unwrap().l);
+        let white = Palette::generate(Srgb::from_str("#FAFAFA").unwrap(), &PaletteRamp::default());
+        assert!(white.get_color(50).unwrap().l > white.get_color(100).unwrap().l);
     }
 }
@@ -0,0 +1,72 @@
+use super::*;
+
+/// How [`Palette::generate`] derives the weights of a palette from one color
+#[derive(Clone, Debug)]
+pub struct PaletteRamp {
+    /// OKLab lightness of each weight, relative to the given color at `500`
+    pub lightness: BTreeMap<u32, f32>,
+    /// How much chroma is lost towards the lightest weight, `0.0..=1.0`
+    pub light_falloff: f32,
+    /// How much chroma is lost towards the darkest weight, `0.0..=1.0`
+    pub dark_falloff: f32,
+}
+
+impl Default for PaletteRamp {
+    /// The lightness curve of the builtin palettes
+    fn default() -> Self {
+        let lightness = [
+            (50, 0.971),
+            (100, 0.936),
+            (200, 0.885),
+            (300, 0.808),
+            (400, 0.704),
+            (500, 0.637),
+            (600, 0.577),
+            (700, 0.505),
+            (800, 0.444),
+            (900, 0.396),
+            (950, 0.258),
+        ];
+        Self { lightness: BTreeMap::from(lightness), light_falloff: 0.9, dark_falloff: 0.4 }
+    }
+}
+
+impl Palette {
+    /// Derive a palette of all weights in the ramp, with the given color at `500`
+    ///
+    /// The lightness follows the curve of the ramp, stretched so that it passes through the given color,
+    /// it keeps decreasing with the weight even for a color near black or white.
+    /// The hue is kept, the chroma fades out towards both ends.
+    pub fn generate(color: impl Into<Oklab>, ramp: &PaletteRamp) -> Self {
+        let base: Oklab = color.into();
+        let (chroma, hue) = (base.chroma(), base.hue());
+        let curve = |w: u32| ramp.lightness.get(&w).copied();
+        let middle = curve(500).unwrap_or(base.l);
+        let lightest = ramp.lightness.values().copied().fold(middle, f32::max);
+        let darkest = ramp.lightness.values().copied().fold(middle, f32::min);
+        // a color lighter or darker than the middle of the curve moves that end towards white or black,
+        // so the ends stay beyond the color and the weights keep getting darker
+        let lightest = lightest.max(1.0 - (1.0 - base.l) * (1.0 - lightest) / (1.0 - middle));
+        let darkest = darkest.min(base.l * darkest / middle);
+        let mut key_points = BTreeMap::new();
+        for (weight, l) in &ramp.lightness {
+            // how far this weight is from the middle, towards the lightest or the darkest end
+            let (t, end, falloff) = match *l >= middle {
+                true if lightest > middle => ((l - middle) / (lightest - middle), lightest, ramp.light_falloff),
+                false if darkest < middle => ((middle - l) / (middle - darkest), darkest, ramp.dark_falloff),
+                _ => (0.0, middle, 0.0),
+            };
+            let l = base.l + (end - base.l) * t;
+            let mut c = chroma * (1.0 - falloff * t * t);
+            let mut out = Oklab::from_lch(l, c, hue, base.alpha);
+            // reduce the chroma until the color fits in sRGB, so that the hue is not shifted by clamping
+            while !out.in_gamut() && c > 0.001 {
+                c *= 0.95;
+                out = Oklab::from_lch(l, c, hue, base.alpha);
+            }
+            key_points.insert(*weight, out);
+        }
+        key_points.insert(500, base);
+        Self { gradient: true, key_points }
+    }
+}
@@ -6,35 +6,77 @@ mod builtin;
 #[derive(Clone, Debug, Default)]
 pub struct PaletteSystem {
     inner: HashMap<String, Palette>,
-    keywords: HashMap<String, Srgb>,
+    keywords: HashMap<String, Oklab>,
 }
 
 impl PaletteSystem {
-    pub fn try_get_color(&self, name: &str, weight: u32) -> Result<Srgb> {
+    pub fn try_get_color(&self, name: &str, weight: u32) -> Result<Oklab> {
         match self.inner.get(name) {
             Some(p) => p.get_color(weight),
             None => syntax_error!("no such palette"),
         }
     }
 
-    pub fn try_get_keyword_color(&self, name: &str) -> Result<&Srgb> {
+    pub fn try_get_keyword_color(&self, name: &str) -> Result<&Oklab> {
         match self.keywords.get(name) {
             Some(color) => Ok(color),
             None => syntax_error!("no such keyword in palette '{}'", name),
         }
     }
 
+    /// Resolve the name of a `--color-*` variable, `primary`, `brand-light` or `red-500`
+    pub fn try_get_variable(&self, name: &str) -> Result<Oklab> {
+        if let Ok(o) = self.try_get_keyword_color(name) {
+            return Ok(*o);
+        }
+        match name.rsplit_once('-').map(|(name, weight)| (name, weight.parse::<u32>())) {
+            Some((name, Ok(weight))) => self.try_get_color(name, weight),
+            _ => syntax_error!("no such color variable '--color-{}'", name),
+        }
+    }
+
     #[inline]
     pub fn register(&mut self, name: String, colors: Palette) -> Option<Palette> {
         self.inner.insert(name, colors)
     }
 
-    /// Registers a keyword from any valid CSS color string (e.g., "#FFF", "rgba(...)").
-    /// - uses the `css_color` crate for parsing.
+    /// Merge the colors into an existing palette, or register a new one
+    pub fn register_merged(&mut self, name: String, colors: Palette) {
+        match self.inner.get_mut(&name) {
+            Some(p) => p.extend(colors),
+            None => {
+                self.inner.insert(name, colors);
+            },
+        }
+    }
+
+    /// Register a palette of all weights `50..=950` derived from one color, e.g. `brand` => `#9A66FF`
+    #[inline]
+    pub fn register_generated(&mut self, name: String, color_string: &str) -> Result<()> {
+        self.register_generated_with(name, color_string, &PaletteRamp::default())
+    }
+
+    /// Register a palette derived from one color, with a custom lightness curve and chroma falloff
+    pub fn register_generated_with(&mut self, name: String, color_string: &str, ramp: &PaletteRamp) -> Result<()> {
+        let color = self.parse_color(color_string)?;
+        self.inner.insert(name, Palette::generate(color, ramp));
+        Ok(())
+    }
+
+    /// Registers a keyword from any valid CSS color string (e.g., "#FFF", "rgba(...)", "oklch(...)").
+    /// - see [`PaletteSystem::parse_color`]
     #[inline]
     pub fn register_keyword(&mut self, name: String, color_string: &str) -> Result<()> {
-        let color = TailwindArbitrary::from(color_string).as_color()?;
+        let color = self.parse_color(color_string)?;
         self.keywords.insert(name, color);
         Ok(())
     }
+
+    /// Parse a color of the theme, `var(--color-red-500)` refers to a registered color
+    pub fn parse_color(&self, color_string: &str) -> Result<Oklab> {
+        match color_string.trim().strip_prefix("var(--color-").and_then(|s| s.strip_suffix(')')) {
+            Some(name) => self.try_get_variable(name.trim()),
+            None => Oklab::from_str(color_string),
+        }
+    }
 }
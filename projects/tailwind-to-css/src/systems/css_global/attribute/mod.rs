@@ -53,6 +53,15 @@ impl CssAttributes {
         }
     }
 
+    /// Mark every property as `!important`, including the nested rules.
+    pub fn set_important(&mut self) {
+        self.normal.set_important();
+        self.transforms.set_important();
+        self.backdrop_filter.set_important();
+        self.filter.set_important();
+        self.nested.values_mut().for_each(|n| n.set_important());
+    }
+
     /// Inserts a nested selector and its attributes.
     ///
     /// # Arguments
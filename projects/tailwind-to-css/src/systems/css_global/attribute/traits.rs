@@ -40,5 +40,13 @@ impl AddAssign<Self> for CssAttributes {
         self.transforms += rhs.transforms;
         self.filter += rhs.filter;
         self.backdrop_filter += rhs.backdrop_filter;
+        for (selector, nested) in rhs.nested {
+            match self.nested.get_mut(&selector) {
+                Some(o) => **o += *nested,
+                None => {
+                    self.nested.insert(selector, nested);
+                },
+            }
+        }
     }
 }
@@ -18,6 +18,10 @@ impl ImportantSet {
     pub fn is_empty(&self) -> bool {
         self.set.is_empty()
     }
+    /// Mark the whole set as `!important`
+    pub fn set_important(&mut self) {
+        self.important = true
+    }
 }
 
 impl ImportantMap {
@@ -35,4 +39,8 @@ impl ImportantMap {
     {
         self.map.insert(key.into(), (true, value.into())).is_some()
     }
+    /// Mark every property as `!important`
+    pub fn set_important(&mut self) {
+        self.map.values_mut().for_each(|(important, _)| *important = true)
+    }
 }
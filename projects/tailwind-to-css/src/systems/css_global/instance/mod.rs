@@ -1,5 +1,5 @@
 use super::*;
-use crate::Base62;
+use crate::{Base62, ScreenRange, TailwindInstruction};
 
 mod traits;
 
@@ -9,6 +9,7 @@ pub(crate) struct CssInstance {
     pub inlineable: bool,
     pub obfuscate: bool,
     pub selector: String,
+    pub variants: Vec<CssVariant>,
     pub attribute: CssAttributes,
     pub addition: String,
 }
@@ -17,16 +18,53 @@ pub(crate) struct CssInstance {
 impl CssInstance {
     pub fn new(item: &dyn TailwindInstance, ctx: &TailwindBuilder, obfuscate: bool) -> Self {
         Self {
-            obfuscate,
+            obfuscate: obfuscate && item.obfuscatable(),
             inlineable: item.inlineable(),
             selector: item.id(),
+            variants: vec![],
             attribute: item.attributes(ctx),
             addition: item.additional(ctx),
         }
     }
+    /// Attach the variants of the instruction, e.g. `hover:` or `print:`
+    ///
+    /// Rules with variants can never be inlined.
+    pub fn with_variants(mut self, item: &TailwindInstruction, ctx: &TailwindBuilder) -> Result<Self> {
+        let variants = item.view_variants();
+        if variants.is_empty() {
+            return Ok(self);
+        }
+        self.selector = format!("{}{}", variants.iter().join(""), self.selector);
+        let mut screen: Option<ScreenRange> = None;
+        self.variants = Vec::with_capacity(variants.len());
+        for variant in variants {
+            match variant.get_variant(ctx)? {
+                CssVariant::Screen(range) => screen = Some(screen.map_or(range, |s| s.intersect(range))),
+                other => self.variants.push(other),
+            }
+        }
+        // `md:max-xl:` is a single range
+        if let Some(range) = screen {
+            self.variants.insert(0, CssVariant::Screen(range));
+        }
+        self.inlineable = false;
+        Ok(self)
+    }
+
+    /// Mark all declarations as `!important` if the instruction ends with `!`
+    pub fn with_important(mut self, item: &TailwindInstruction) -> Self {
+        if item.is_important() {
+            self.selector.push('!');
+            self.attribute.set_important();
+        }
+        self
+    }
 
     pub fn obfuscate(css: &Self) -> String {
         let mut hasher = Xxh3::new();
+        if !css.variants.is_empty() {
+            css.variants.hash(&mut hasher);
+        }
         css.attribute.hash(&mut hasher);
         css.addition.hash(&mut hasher);
         hasher.finish().base62()
@@ -37,13 +75,48 @@ impl CssInstance {
             false => self.selector.to_string(),
         }
     }
-    /// write css to buffers
+    /// Is this rule wrapped by any at-rule, such as `@media`
+    pub fn is_wrapped(&self) -> bool {
+        self.variants.iter().any(|v| matches!(v, CssVariant::AtRule(_)))
+    }
+    /// Rules are written in this order, so that variants can override the plain rules
+    ///
+    /// - `0`: plain rules
+    /// - `1`: rules with selector variants, such as `hover:`
+    /// - `2`: rules wrapped by at-rules, such as `print:`
+    pub fn get_order(&self) -> u8 {
+        if self.is_wrapped() {
+            2
+        }
+        else if self.variants.iter().any(|v| matches!(v, CssVariant::Selector(_))) {
+            1
+        }
+        else {
+            0
+        }
+    }
+    /// The responsive range of this rule, such as `md:`
+    pub fn get_screen(&self) -> Option<ScreenRange> {
+        self.variants.iter().find_map(|v| match v {
+            CssVariant::Screen(range) => Some(*range),
+            _ => None,
+        })
+    }
+    /// write css to buffers, the screen is written by the builder
     pub fn write_css(&self, f: &mut (dyn Write)) -> Result<()> {
-        f.write_char('.')?;
-        normalize_class_name(f, &self.get_class())?;
+        let mut class = String::from(".");
+        normalize_class_name(&mut class, &self.get_class())?;
+        let (selector, wrappers) = CssVariant::apply(&self.variants, &class);
+        for wrapper in &wrappers {
+            write!(f, "{}{{", wrapper)?;
+        }
+        f.write_str(&selector)?;
         f.write_char('{')?;
         write!(f, "{}", self.attribute)?;
         f.write_char('}')?;
+        for _ in &wrappers {
+            f.write_char('}')?;
+        }
         write!(f, "{}", self.addition)?;
         Ok(())
     }
@@ -14,7 +14,8 @@ use crate::{Result, TailwindBuilder, TailwindInstance};
 pub use self::{
     attribute::CssAttributes,
     important::{ImportantMap, ImportantSet},
-    mode::CssInlineMode,
+    mode::{CssInlineMode, DarkMode},
+    variant::CssVariant,
 };
 pub(crate) use self::{bundle::CssBundle, instance::CssInstance};
 
@@ -23,6 +24,7 @@ mod bundle;
 mod important;
 mod instance;
 mod mode;
+mod variant;
 
 /// Normalize class name as a valid CSS selector
 /// - Escapes non-alphanumeric characters with a backslash (`\`).
@@ -29,3 +29,26 @@ impl Default for CssInlineMode {
         Self::None
     }
 }
+
+/// How the `dark:` variant is compiled
+///
+/// <https://tailwindcss.com/docs/dark-mode>
+#[derive(Debug, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum DarkMode {
+    /// Follow the operating system preference
+    /// ```css
+    /// @media (prefers-color-scheme: dark) { .dark\:x {} }
+    /// ```
+    #[default]
+    Media,
+    /// Toggle manually with a `dark` class on any ancestor
+    /// ```css
+    /// .dark\:x:where(.dark, .dark *) {}
+    /// ```
+    Class,
+    /// Toggle manually with a custom selector on any ancestor, e.g. `[data-theme=dark]`
+    /// ```css
+    /// .dark\:x:where([data-theme=dark], [data-theme=dark] *) {}
+    /// ```
+    Selector(String),
+}
@@ -0,0 +1,42 @@
+use super::*;
+use crate::ScreenRange;
+
+/// The css produced by a single resolved variant, e.g. `hover:` or `print:`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum CssVariant {
+    /// A selector template, every `&` is replaced by the selector of the rule.
+    ///
+    /// - `hover:` => `&:hover`
+    /// - `children:` => `& > *`
+    Selector(String),
+    /// An at-rule which wraps the whole rule.
+    ///
+    /// - `print:` => `@media print`
+    /// - `sticky:` => `@supports (position: sticky)`
+    AtRule(String),
+    /// A responsive range, rules sharing the same range are grouped into one `@media` block.
+    ///
+    /// - `md:` => `@media (min-width: 768px)`
+    /// - `max-md:` => `@media (max-width: 767.98px)`
+    Screen(ScreenRange),
+}
+
+impl CssVariant {
+    /// Apply all variants in order to the class selector.
+    ///
+    /// # Returns
+    /// - `.0`: the final selector
+    /// - `.1`: the at-rules, outermost first, screens are excluded
+    pub fn apply<'a>(variants: &'a [CssVariant], class: &str) -> (String, Vec<&'a str>) {
+        let mut selector = class.to_string();
+        let mut wrappers = vec![];
+        for variant in variants {
+            match variant {
+                Self::Selector(template) => selector = template.replace('&', &selector),
+                Self::AtRule(rule) => wrappers.push(rule.as_str()),
+                Self::Screen(_) => {},
+            }
+        }
+        (selector, wrappers)
+    }
+}
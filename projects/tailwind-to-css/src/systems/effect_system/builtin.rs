@@ -19,11 +19,11 @@ impl EffectSystem {
     }
     fn builtin_drop_shadows(&mut self) {
         self.set_drop_shadow_default("drop-shadow(0 1px 2px rgb(0 0 0 / 0.1)) drop-shadow(0 1px 1px rgb(0 0 0 / 0.06))");
-        self.insert_drop_shadow("sm", "drop-shadow(0 1px 1px rgb(0 0 0 / 0.05));");
-        self.insert_drop_shadow("md", "drop-shadow(0 4px 3px rgb(0 0 0 / 0.07)) drop-shadow(0 2px 2px rgb(0 0 0 / 0.06));");
-        self.insert_drop_shadow("lg", "drop-shadow(0 10px 8px rgb(0 0 0 / 0.04)) drop-shadow(0 4px 3px rgb(0 0 0 / 0.1));");
-        self.insert_drop_shadow("xl", "drop-shadow(0 20px 13px rgb(0 0 0 / 0.03)) drop-shadow(0 8px 5px rgb(0 0 0 / 0.08));");
-        self.insert_drop_shadow("2xl", "drop-shadow(0 25px 25px rgb(0 0 0 / 0.15));");
-        self.insert_drop_shadow("none", "drop-shadow(0 0 #0000);");
+        self.insert_drop_shadow("sm", "drop-shadow(0 1px 1px rgb(0 0 0 / 0.05))");
+        self.insert_drop_shadow("md", "drop-shadow(0 4px 3px rgb(0 0 0 / 0.07)) drop-shadow(0 2px 2px rgb(0 0 0 / 0.06))");
+        self.insert_drop_shadow("lg", "drop-shadow(0 10px 8px rgb(0 0 0 / 0.04)) drop-shadow(0 4px 3px rgb(0 0 0 / 0.1))");
+        self.insert_drop_shadow("xl", "drop-shadow(0 20px 13px rgb(0 0 0 / 0.03)) drop-shadow(0 8px 5px rgb(0 0 0 / 0.08))");
+        self.insert_drop_shadow("2xl", "drop-shadow(0 25px 25px rgb(0 0 0 / 0.15))");
+        self.insert_drop_shadow("none", "drop-shadow(0 0 #0000)");
     }
 }
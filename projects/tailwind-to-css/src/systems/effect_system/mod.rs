@@ -11,8 +11,12 @@ pub struct EffectSystem {
 }
 
 impl EffectSystem {
-    pub fn get_box_shadow(&self, name: &str) -> String {
-        self.box_shadows.get(name).unwrap_or(&self.box_shadow_default).clone()
+    /// The named box shadow, `""` is the default one of `shadow`
+    pub fn get_box_shadow(&self, name: &str) -> Option<String> {
+        match name {
+            "" => Some(self.box_shadow_default.clone()),
+            _ => self.box_shadows.get(name).cloned(),
+        }
     }
     pub fn insert_box_shadow<K, V>(&mut self, key: K, value: V)
     where
@@ -27,8 +31,16 @@ impl EffectSystem {
     {
         self.box_shadow_default = value.into();
     }
-    pub fn get_drop_shadow(&self, name: &str) -> String {
-        self.box_shadows.get(name).unwrap_or(&self.drop_shadow_default).clone()
+    /// Remove all named box shadows, the default one is kept
+    pub fn clear_box_shadows(&mut self) {
+        self.box_shadows.clear()
+    }
+    /// The named drop shadow, `""` is the default one of `drop-shadow`
+    pub fn get_drop_shadow(&self, name: &str) -> Option<String> {
+        match name {
+            "" => Some(self.drop_shadow_default.clone()),
+            _ => self.drop_shadows.get(name).cloned(),
+        }
     }
     pub fn insert_drop_shadow<K, V>(&mut self, key: K, value: V)
     where
@@ -43,4 +55,8 @@ impl EffectSystem {
     {
         self.drop_shadow_default = value.into();
     }
+    /// Remove all named drop shadows, the default one is kept
+    pub fn clear_drop_shadows(&mut self) {
+        self.drop_shadows.clear()
+    }
 }
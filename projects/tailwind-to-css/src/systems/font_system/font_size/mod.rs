@@ -3,7 +3,7 @@ use super::*;
 #[derive(Copy, Debug, Clone)]
 pub struct FontSize {
     size: LengthUnit,
-    height: LengthUnit,
+    height: Option<LengthUnit>,
 }
 
 impl FontSize {
@@ -11,12 +11,27 @@ impl FontSize {
     pub fn new(size: f32, height: f32) -> Self {
         let size = LengthUnit::rem(size);
         let height = if height > 0.0 { LengthUnit::rem(height) } else { LengthUnit::percent(-height) };
-        Self { size, height }
+        Self { size, height: Some(height) }
+    }
+    /// Parse a configured font size, e.g. `0.875rem` with line height `1.25rem` or unitless `1.5`
+    pub fn parse(size: &str, height: Option<&str>) -> Result<Self> {
+        let size = LengthUnit::parse_length(size)?;
+        let height = match height {
+            Some(h) => match h.parse::<f32>() {
+                Ok(o) => Some(LengthUnit::Unit(o, "")),
+                Err(_) => Some(LengthUnit::parse_length(h)?),
+            },
+            None => None,
+        };
+        Ok(Self { size, height })
     }
     pub fn get_properties(&self) -> CssAttributes {
-        css_attributes! {
+        let mut css = css_attributes! {
             "font-size" => self.size.get_properties(),
-            "line-height" => self.height.get_properties(),
+        };
+        if let Some(height) = &self.height {
+            css.insert("line-height", height.get_properties());
         }
+        css
     }
 }
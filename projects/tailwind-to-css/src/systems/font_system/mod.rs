@@ -1,5 +1,5 @@
 pub use self::font_size::FontSize;
-use crate::{css_attributes, CssAttributes, LengthUnit};
+use crate::{css_attributes, CssAttributes, LengthUnit, Result};
 use std::collections::BTreeMap;
 
 mod builtin;
@@ -20,11 +20,21 @@ impl FontSystem {
             Some(s) => s,
         }
     }
+    /// Get the named font size, `None` if not registered
+    #[inline]
+    pub fn try_get_size(&self, name: &str) -> Option<FontSize> {
+        self.size.get(name).cloned()
+    }
     /// Insert a new font size
     #[inline]
     pub fn insert_size(&mut self, name: impl Into<String>, size: FontSize) -> Option<FontSize> {
         self.size.insert(name.into(), size)
     }
+    /// Remove all font sizes, including the builtin ones
+    #[inline]
+    pub fn clear_size(&mut self) {
+        self.size.clear()
+    }
     /// Get the named font family,
     ///
     /// never fail, fallback to the `serif, sans-serif, monospace`
@@ -41,17 +51,45 @@ impl FontSystem {
         let family = Self::normalize_family(family)?;
         self.family.insert(name.into(), family)
     }
+    /// Remove all font families, including the builtin ones
+    #[inline]
+    pub fn clear_family(&mut self) {
+        self.family.clear()
+    }
     #[inline]
     fn normalize_family(input: &str) -> Option<Vec<String>> {
         Some(vec![input.to_string()])
     }
     #[inline]
-    pub fn get_tracking(&self, name: &str) -> f32 {
-        self.tracking.get(name).cloned().unwrap_or(0.0)
+    pub fn get_tracking(&self, name: &str) -> Option<f32> {
+        self.tracking.get(name).cloned()
     }
     /// Insert a new font size
     #[inline]
     pub fn insert_tracking(&mut self, name: impl Into<String>, size: f32) -> Option<f32> {
         self.tracking.insert(name.into(), size)
     }
+    /// Remove all letter spacings, including the builtin ones
+    #[inline]
+    pub fn clear_tracking(&mut self) {
+        self.tracking.clear()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TailwindBuilder;
+
+    #[test]
+    fn test_font_family() {
+        let mut builder = TailwindBuilder::default();
+        assert!(builder.inline("font-sans").unwrap().1.starts_with("font-family:ui-sans-serif"));
+        assert_eq!(builder.inline("font-700").unwrap().1, "font-weight:700;");
+        // only the families of the theme are known
+        for class in ["font-xyz", "font-family", "font-family-xyz", "font-my-face"] {
+            assert!(builder.inline(class).is_err(), "{}", class);
+        }
+        builder.fonts.clear_family();
+        assert!(builder.inline("font-sans").is_err());
+    }
 }
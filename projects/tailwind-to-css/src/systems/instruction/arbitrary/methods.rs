@@ -41,4 +41,30 @@ impl TailwindArbitrary {
     pub fn as_color(&self) -> Result<Srgb> {
         Ok(Srgb::from_str(&self.inner)?)
     }
+    /// The modifier after the slash, `50` in `bg-red-500/50` or `7` in `text-lg/7`
+    #[inline]
+    pub fn as_modifier(&self) -> Option<&str> {
+        self.modifier.as_deref()
+    }
+    #[inline]
+    pub(crate) fn with_modifier(mut self, modifier: Option<&str>) -> Self {
+        self.modifier = modifier.map(Box::from);
+        self
+    }
+    /// The value with a `color:` type hint removed, and `_` written as spaces
+    pub fn as_color_value(&self) -> String {
+        self.inner.strip_prefix("color:").unwrap_or(&self.inner).replace('_', " ")
+    }
+    /// Can only be a color, e.g. `#fff`, `color:var(--x)`, `oklch(...)`, `theme(colors.red.500)`
+    ///
+    /// A `var()` is a color when it carries an opacity modifier, `[var(--brand)]/50`
+    pub fn is_color(&self) -> bool {
+        const FUNCTIONS: &[&str] =
+            &["rgb(", "rgba(", "hsl(", "hsla(", "hwb(", "lab(", "lch(", "oklab(", "oklch(", "color(", "color-mix(", "theme(colors."];
+        let value = self.inner.as_ref();
+        value.starts_with("color:")
+            || value.starts_with('#')
+            || FUNCTIONS.iter().any(|f| value.starts_with(f))
+            || (value.starts_with("var(") && self.modifier.is_some())
+    }
 }
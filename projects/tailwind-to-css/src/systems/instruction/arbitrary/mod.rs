@@ -6,6 +6,8 @@ mod methods;
 #[derive(Debug, Clone)]
 pub struct TailwindArbitrary {
     inner: Box<str>,
+    /// The modifier after the slash, `50` in `bg-red-500/50` or `7` in `text-lg/7`
+    modifier: Option<Box<str>>,
 }
 
 impl Display for TailwindArbitrary {
@@ -23,13 +25,13 @@ impl Display for TailwindArbitrary {
 
 impl From<&str> for TailwindArbitrary {
     fn from(s: &str) -> Self {
-        Self { inner: Box::from(s) }
+        Self { inner: Box::from(s), modifier: None }
     }
 }
 
 impl From<&Self> for TailwindArbitrary {
     fn from(s: &Self) -> Self {
-        Self { inner: s.inner.clone() }
+        Self { inner: s.inner.clone(), modifier: s.modifier.clone() }
     }
 }
 
@@ -39,10 +41,11 @@ impl TailwindArbitrary {
         T: Into<Self>,
     {
         let out = s.into();
+        // `text-align` is no class, the arbitrary value of `text-align-[...]` is missing
+        if out.inner.is_empty() {
+            return Err(TailwindError::syntax_error("Arbitrary value cannot be empty"));
+        }
         if cfg!(feature = "compile_time") {
-            if out.inner.is_empty() {
-                return Err(TailwindError::syntax_error("Arbitrary value cannot be empty"));
-            }
             // TODO: Check unbalanced quotes
             if out.inner.contains('\n') {
                 return Err(TailwindError::syntax_error("Arbitrary value does balance quotes"));
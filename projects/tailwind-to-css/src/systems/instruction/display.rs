@@ -7,22 +7,25 @@ impl Display for TailwindInstruction {
         }
         self.negative.write(f)?;
         match self.arbitrary.is_some() {
-            true => write!(f, "{}-{}", self.elements, self.arbitrary.get_class()),
-            false => write!(f, "{}", self.elements),
+            true => write!(f, "{}-{}", self.elements, self.arbitrary.get_class())?,
+            false => write!(f, "{}", self.elements)?,
         }
+        if let Some(s) = self.arbitrary.as_modifier() {
+            write!(f, "/{}", s)?
+        }
+        if self.important {
+            write!(f, "!")?
+        }
+        Ok(())
     }
 }
 
 impl TailwindInstance for TailwindInstruction {
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let mut out = CssAttributes::default();
-        match self.get_instance() {
-            Ok(o) => {
-                out += o.attributes(ctx);
-            },
-            Err(e) => {
-                unimplemented!("{}", e);
-            },
+        // an unknown instruction has no attributes, `TailwindBuilder::diagnostics` reports it
+        if let Ok(o) = self.get_instance() {
+            out += o.attributes(ctx);
         }
         out
     }
@@ -34,6 +37,14 @@ impl Display for TailwindVariant {
             write!(f, "not-")?
         }
         write!(f, "{}", self.names.join("-"))?;
+        match &self.arbitrary {
+            Some(s) if self.names.is_empty() => write!(f, "[{}]", s)?,
+            Some(s) => write!(f, "-[{}]", s)?,
+            None => {},
+        }
+        if let Some(s) = &self.modifier {
+            write!(f, "/{}", s)?
+        }
         match self.pseudo {
             true => {
                 write!(f, "::")
@@ -1,19 +1,42 @@
 use super::*;
 
+/// The utilities reading the `/modifier` themselves, the colors and `text-lg/7`
+///
+/// `grid-cols-[1fr_2fr]/2` is not listed: Tailwind gives a modifier on a grid template no meaning,
+/// so it is rejected instead of guessing one.
+const MODIFIED: &[&str] =
+    &["bg", "from", "via", "to", "border", "outline", "ring", "shadow", "accent", "caret", "fill", "stroke", "decoration", "text"];
+
 impl<'a> From<AstStyle<'a>> for TailwindInstruction {
     fn from(node: AstStyle<'a>) -> Self {
+        let mut elements: Vec<String> = node.elements.iter().map(|s| s.to_string()).collect();
+        let mut modifier = node.modifier;
+        // `w-1/2` is a fraction rather than a modifier
+        if let (Some(m), None, Some(last)) = (modifier, node.arbitrary, elements.last_mut()) {
+            if !Self::takes_modifier(&node.elements) {
+                *last = format!("{}/{}", last, m);
+                modifier = None;
+            }
+        }
         Self {
+            important: node.important,
             negative: Negative::from(node.negative),
             variants: node.variants.into_iter().map(|s| s.into()).collect(),
-            elements: TailwindElements { inner: node.elements.into_iter().map(|s| s.to_string()).collect() },
-            arbitrary: TailwindArbitrary::from(node.arbitrary.unwrap_or_default()),
+            elements: TailwindElements { inner: elements },
+            arbitrary: TailwindArbitrary::from(node.arbitrary.unwrap_or_default()).with_modifier(modifier),
         }
     }
 }
 
 impl<'a> From<ASTVariant<'a>> for TailwindVariant {
     fn from(node: ASTVariant<'a>) -> Self {
-        Self { not: node.not, pseudo: node.pseudo, names: node.names.into_iter().map(|s| s.to_string()).collect() }
+        Self {
+            not: node.not,
+            pseudo: node.pseudo,
+            names: node.names.into_iter().map(|s| s.to_string()).collect(),
+            arbitrary: node.arbitrary.map(|s| s.to_string()),
+            modifier: node.modifier.map(|s| s.to_string()),
+        }
     }
 }
 
@@ -23,9 +46,22 @@ impl TailwindInstruction {
         self.elements.inner.iter().map(|s| s.as_str()).collect()
     }
     #[inline]
+    pub fn view_variants(&self) -> &[TailwindVariant] {
+        &self.variants
+    }
+    #[inline]
     pub fn view_arbitrary(&self) -> &TailwindArbitrary {
         &self.arbitrary
     }
+    #[inline]
+    pub fn is_important(&self) -> bool {
+        self.important
+    }
+    /// Whether the utility reads the `/modifier`, `bg-red-500/50` or `text-lg/7`
+    #[inline]
+    pub fn takes_modifier<S: AsRef<str>>(elements: &[S]) -> bool {
+        elements.first().is_some_and(|head| MODIFIED.contains(&head.as_ref()))
+    }
     // TODO
     pub fn normalization(self) -> Self {
         self
@@ -2,6 +2,7 @@ mod arbitrary;
 mod display;
 mod methods;
 mod resolver;
+mod variant;
 pub use self::arbitrary::TailwindArbitrary;
 use crate::{TailwindBuilder, *};
 use css_color::Srgb;
@@ -14,6 +15,7 @@ use tailwind_ast::{parse_fraction, ASTVariant, AstStyle};
 /// `v:v:-a-a-[A]`
 #[derive(Debug, Clone)]
 pub struct TailwindInstruction {
+    important: bool,
     negative: Negative,
     variants: Vec<TailwindVariant>,
     elements: TailwindElements,
@@ -25,6 +27,8 @@ pub struct TailwindVariant {
     not: bool,
     pseudo: bool,
     names: Vec<String>,
+    arbitrary: Option<String>,
+    modifier: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -33,7 +37,7 @@ pub struct TailwindElements {
 }
 
 /// <https://github.com/tw-in-js/twind/blob/main/src/twind/variants.ts>
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TailwindVariantKind {
     Dark,
     Sticky,
@@ -47,4 +51,12 @@ pub enum TailwindVariantKind {
     Siblings,
     Sibling,
     Override,
+    /// `hover:`, `focus-visible:`
+    PseudoClass(&'static str),
+    /// `before::`, `placeholder::`
+    PseudoElement(&'static str),
+    /// `print:`, `portrait:`
+    Media(&'static str),
+    /// `prose-headings:`, `prose-a:`
+    Prose(&'static str),
 }
@@ -1,5 +1,24 @@
 use super::*;
 
+/// The utilities known to [`TailwindInstruction::get_instance`], for "did you mean" suggestions, kept in sync by a test
+#[rustfmt::skip]
+const PREFIXES: &[&str] = &[
+    "aspect", "container", "columns", "break", "box", "block", "inline", "flow-root", "grid", "contents", "list", "hidden",
+    "float", "clear", "isolate", "isolation", "object", "overflow", "overscroll", "static", "fixed", "absolute",
+    "relative", "sticky", "position", "inset", "top", "right", "bottom", "left", "start", "end", "invisible", "visible",
+    "visibility", "z", "basis", "flex", "grow", "shrink", "order", "col", "row", "auto", "gap", "justify", "content",
+    "items", "self", "place", "p", "pl", "pr", "pb", "pt", "px", "py", "m", "ml", "mr", "mb", "mt", "mx", "my", "space",
+    "w", "min-w", "max-w", "h", "min-h", "max-h", "font", "text", "antialiased", "subpixel-antialiased", "italic",
+    "not-italic", "normal-nums", "ordinal", "slashed-zero", "lining-nums", "oldstyle-nums", "proportional-nums",
+    "tabular-nums", "diagonal-fractions", "stacked-fractions", "tracking", "leading", "underline", "overline",
+    "line-through", "no-underline", "decoration", "uppercase", "lowercase", "capitalize", "normal-case", "truncate",
+    "indent", "align", "whitespace", "prose", "bg", "from", "via", "to", "rounded", "border", "divide", "outline", "ring",
+    "shadow", "opacity", "mix-blend", "mask", "blur", "brightness", "contrast", "drop-shadow", "grayscale", "hue-rotate",
+    "invert", "saturate", "sepia", "backdrop", "table", "transition", "duration", "ease", "delay", "animate", "scale",
+    "rotate", "translate", "skew", "origin", "accent", "appearance", "cursor", "caret", "pointer-events", "resize",
+    "scroll", "snap", "touch", "select", "will-change", "fill", "stroke", "sr-only", "not-sr-only", "peer", "group",
+];
+
 // noinspection SpellCheckingInspection
 impl TailwindInstruction {
     #[inline(never)]
@@ -8,6 +27,9 @@ impl TailwindInstruction {
         let pattern = element.as_slice();
         let arbitrary = self.view_arbitrary();
         let neg = self.negative;
+        if arbitrary.as_modifier().is_some() && !Self::takes_modifier(pattern) {
+            return syntax_error!("{} does not take a `/` modifier", self);
+        }
         let instance = match pattern {
             // Layout System
             ["aspect", rest @ ..] => TailwindAspect::parse(rest, arbitrary)?.boxed(),
@@ -124,7 +146,7 @@ impl TailwindInstruction {
             // break catched
             // content catched
             // Typography System Extension
-            ["prose"] => todo!(),
+            ["prose", rest @ ..] => TailwindProse::parse(rest, arbitrary)?.boxed(),
             // Backgrounds System
             ["bg", rest @ ..] => Self::bg_adaptor(rest, arbitrary, neg)?,
             ["from", rest @ ..] => TailwindFrom::parse(rest, arbitrary)?.boxed(),
@@ -188,6 +210,9 @@ impl TailwindInstruction {
             // Contextual Markers for Variants
             ["peer"] => TailwindVariantMarker::parse(pattern, arbitrary)?.boxed(),
             ["group"] => TailwindVariantMarker::parse(pattern, arbitrary)?.boxed(),
+            [s, ..] if s.starts_with("peer/") || s.starts_with("group/") => {
+                TailwindVariantMarker::parse(pattern, arbitrary)?.boxed()
+            },
             // Form System Extension
             _ => return syntax_error!("Unknown instructions: {} + {}", element.join("-"), arbitrary.get_class()),
         };
@@ -240,12 +265,12 @@ impl TailwindInstruction {
             ["collapse", rest @ ..] => TailwindBorderCollapse::parse(rest, arbitrary)?.boxed(),
             // https://tailwindcss.com/docs/border-width
             [] => {
-                if arbitrary.as_str().starts_with('#') {
-                    TailwindBorderColor::from(arbitrary.as_color()?).boxed()
+                if arbitrary.is_color() {
+                    TailwindBorderColor::parse_arbitrary(arbitrary)?.boxed()
                 } else {
                     TailwindBorderWidth::parse(pattern, arbitrary)?.boxed()
                 }
-            }, // e.g. border-[3px] or border-[#hex]
+            }, // e.g. border-[3px] or border-[#hex], border-[color:var(--x)]
             ["0" | "2" | "4" | "8", ..] if arbitrary.is_none() => TailwindBorderWidth::parse(pattern, arbitrary)?.boxed(), // e.g. border-4
             ["x" | "y" | "t" | "r" | "b" | "l", ..] => TailwindBorderWidth::parse(pattern, arbitrary)?.boxed(), // e.g. border-x-2
             // https://tailwindcss.com/docs/border-color
@@ -328,3 +353,76 @@ impl TailwindInstruction {
         Ok(out)
     }
 }
+
+impl TailwindInstruction {
+    /// The instruction with its utility replaced by the closest known one, `bgg-red-500` => `bg-red-500`
+    ///
+    /// `None` if the utility is known and only the value is wrong, or nothing is close enough.
+    pub fn suggest(&self) -> Option<String> {
+        let elements = self.view_elements();
+        let mut best: Option<(usize, &str, usize)> = None;
+        for prefix in PREFIXES {
+            let count = prefix.split('-').count();
+            if elements.len() < count {
+                continue;
+            }
+            let head = elements[..count].join("-");
+            let distance = edit_distance(&head, prefix);
+            if distance == 0 {
+                return None;
+            }
+            // allow one typo per three letters
+            let close = distance * 3 <= head.len().max(prefix.len());
+            if close && best.is_none_or(|(d, ..)| distance < d) {
+                best = Some((distance, prefix, count));
+            }
+        }
+        let (_, prefix, count) = best?;
+        let mut out = self.clone();
+        out.elements.inner = prefix.split('-').chain(elements[count..].iter().copied()).map(String::from).collect();
+        Some(out.to_string())
+    }
+}
+
+/// Levenshtein distance where swapping two neighbouring letters counts as one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut d = (rows[i - 1][j] + 1).min(rows[i][j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = d;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every suggested prefix must be one of the match arms of `get_instance`
+    #[test]
+    fn test_prefixes_resolve() {
+        let unknown = |class: &str| {
+            let style = tailwind_ast::parse_tailwind(class).unwrap().remove(0);
+            match TailwindInstruction::from(style).get_instance() {
+                Ok(_) => false,
+                Err(e) => e.to_string().contains("Unknown instructions"),
+            }
+        };
+        for prefix in PREFIXES {
+            assert!(!unknown(prefix) || !unknown(&format!("{}-x", prefix)), "{}", prefix);
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use super::*;
+
+impl TailwindVariant {
+    /// Resolve the variant into the css it produces
+    #[inline]
+    pub fn get_variant(&self, ctx: &TailwindBuilder) -> Result<CssVariant> {
+        if let Some(range) = self.get_screen(ctx)? {
+            return Ok(CssVariant::Screen(range));
+        }
+        if let Some(variant) = self.get_marked(ctx)? {
+            return Ok(variant);
+        }
+        if let Some(variant) = self.get_attribute(ctx)? {
+            return Ok(variant);
+        }
+        self.get_kind()?.get_variant(ctx, self.not)
+    }
+    /// <https://tailwindcss.com/docs/hover-focus-and-other-states#styling-based-on-parent-state>
+    ///
+    /// - `group-hover:` => `.group:hover &`
+    /// - `peer-checked:` => `.peer:checked ~ &`
+    /// - `group-[.is-open]:` => `.group.is-open &`
+    /// - `group-hover/sidebar:` => `.group\/sidebar:hover &`
+    /// - `peer-data-[state=open]:` => `.peer[data-state=open] ~ &`
+    pub fn get_marked(&self, ctx: &TailwindBuilder) -> Result<Option<CssVariant>> {
+        let (peer, state) = match self.names.split_first() {
+            Some((head, rest)) if head == "group" => (false, rest),
+            Some((head, rest)) if head == "peer" => (true, rest),
+            _ => return Ok(None),
+        };
+        let marker = TailwindVariantMarker::new(peer, self.modifier.clone()).get_selector();
+        let selector = match (state, &self.arbitrary) {
+            // `_` stands for space in arbitrary values
+            ([], Some(s)) if s.contains('&') && !self.not => s.replace('_', " ").replace('&', &marker),
+            ([], Some(s)) if !s.contains('&') && self.not => format!("{}:not({})", marker, s.replace('_', " ")),
+            ([], Some(s)) if !s.contains('&') => format!("{}{}", marker, s.replace('_', " ")),
+            (names, arbitrary) if !names.is_empty() => {
+                let inner =
+                    Self { not: self.not, pseudo: false, names: names.to_vec(), arbitrary: arbitrary.clone(), modifier: None };
+                match inner.get_variant(ctx)? {
+                    CssVariant::Selector(s) if s.starts_with("&:") || s.starts_with("&[") => s.replace('&', &marker),
+                    _ => return syntax_error!("{} must be combined with a pseudo class", self),
+                }
+            },
+            _ => return syntax_error!("Unknown variant: {}", self),
+        };
+        match peer {
+            true => Ok(Some(CssVariant::Selector(format!("{} ~ &", selector)))),
+            false => Ok(Some(CssVariant::Selector(format!("{} &", selector)))),
+        }
+    }
+    /// <https://tailwindcss.com/docs/hover-focus-and-other-states#using-arbitrary-variants>
+    ///
+    /// - `[&:nth-child(3)]:` => `&:nth-child(3)`
+    /// - `[@media(hover:hover)]:` => `@media (hover:hover)`
+    /// - `data-[state=open]:`, `data-active:` => `&[data-state=open]`, `&[data-active]`
+    /// - `aria-[sort=ascending]:`, `aria-checked:` => `&[aria-sort=ascending]`, `&[aria-checked="true"]`
+    /// - `has-[:checked]:`, `has-checked:` => `&:has(:checked)`
+    /// - `supports-[display:grid]:` => `@supports (display:grid)`
+    pub fn get_attribute(&self, ctx: &TailwindBuilder) -> Result<Option<CssVariant>> {
+        let names: Vec<&str> = self.names.iter().map(|s| s.as_str()).collect();
+        // `_` stands for space in arbitrary values
+        let arbitrary = self.arbitrary.as_ref().map(|s| s.replace('_', " "));
+        let out = match (names.as_slice(), arbitrary) {
+            ([], Some(s)) if s.starts_with('@') && !self.not => {
+                let (rule, query) = s.split_at(s.find('(').unwrap_or(s.len()));
+                CssVariant::AtRule(format!("{} {}", rule.trim_end(), query).trim_end().to_string())
+            },
+            ([], Some(s)) if s.contains('&') && !self.not => CssVariant::Selector(s),
+            ([], Some(s)) if !s.contains('&') && !s.starts_with('@') => pseudo_class(&format!(":is({})", s), self.not),
+            (["data"], Some(s)) => pseudo_class(&format!("[data-{}]", s), self.not),
+            (["data", rest @ ..], None) if !rest.is_empty() => pseudo_class(&format!("[data-{}]", rest.join("-")), self.not),
+            (["aria"], Some(s)) => pseudo_class(&format!("[aria-{}]", s), self.not),
+            (["aria", rest @ ..], None) if !rest.is_empty() => {
+                pseudo_class(&format!("[aria-{}=\"true\"]", rest.join("-")), self.not)
+            },
+            (["has"], Some(s)) => pseudo_class(&format!(":has({})", s), self.not),
+            (["has", rest @ ..], None) if !rest.is_empty() => {
+                let inner = Self { not: false, pseudo: false, names: self.names[1..].to_vec(), arbitrary: None, modifier: None };
+                match inner.get_kind()?.get_variant(ctx, false)? {
+                    CssVariant::Selector(s) if s.starts_with("&:") => pseudo_class(&format!(":has({})", &s[1..]), self.not),
+                    _ => return syntax_error!("{} must be combined with a pseudo class", self),
+                }
+            },
+            (["supports"], Some(s)) if s.contains('(') => supports(&s, self.not),
+            (["supports"], Some(s)) => supports(&format!("({})", s), self.not),
+            ([], Some(_)) => return syntax_error!("Arbitrary variant {} can not be negated", self),
+            _ => return Ok(None),
+        };
+        if self.pseudo || self.modifier.is_some() {
+            return syntax_error!("Unknown variant: {}", self);
+        }
+        Ok(Some(out))
+    }
+    /// <https://tailwindcss.com/docs/responsive-design>
+    ///
+    /// - `md:`, `min-md:`, `min-[900px]:`
+    /// - `max-md:`, `max-[900px]:`
+    pub fn get_screen(&self, ctx: &TailwindBuilder) -> Result<Option<ScreenRange>> {
+        let names: Vec<&str> = self.names.iter().map(|s| s.as_str()).collect();
+        let screen = |name: &str| ctx.screens.try_get_width(name).ok();
+        let range = match (names.as_slice(), &self.arbitrary) {
+            ([name], None) if screen(name).is_some() => screen(name).map(ScreenRange::min),
+            (["min", name], None) if screen(name).is_some() => screen(name).map(ScreenRange::min),
+            (["max", name], None) if screen(name).is_some() => screen(name).map(ScreenRange::max),
+            (["min"], Some(width)) => Some(ScreenRange::min(BreakPointSystem::parse_width(width)?)),
+            (["max"], Some(width)) => Some(ScreenRange::max(BreakPointSystem::parse_width(width)?)),
+            _ => return Ok(None),
+        };
+        if self.not || self.pseudo {
+            return syntax_error!("Responsive variant {} can not be negated or used as pseudo element", self);
+        }
+        // below `0px` matches no viewport
+        if range.is_some_and(|r| r.max == Some(0)) {
+            return syntax_error!("Responsive variant {} matches no width", self);
+        }
+        Ok(range)
+    }
+    /// <https://tailwindcss.com/docs/hover-focus-and-other-states#quick-reference>
+    pub fn get_kind(&self) -> Result<TailwindVariantKind> {
+        use TailwindVariantKind::*;
+        if self.arbitrary.is_some() || self.modifier.is_some() {
+            return syntax_error!("Unknown variant: {}", self);
+        }
+        let names: Vec<&str> = self.names.iter().map(|s| s.as_str()).collect();
+        let kind = match names.as_slice() {
+            ["dark"] => Dark,
+            ["sticky"] => Sticky,
+            ["motion", "reduce"] => MotionReduce,
+            ["motion", "safe"] => MotionSafe,
+            ["first"] => First,
+            ["last"] => Last,
+            ["even"] => Even,
+            ["odd"] => Odd,
+            ["children"] => Children,
+            ["siblings"] => Siblings,
+            ["sibling"] => Sibling,
+            ["override"] => Override,
+            // https://developer.mozilla.org/en-US/docs/Web/CSS/Pseudo-elements
+            ["before"] => PseudoElement("::before"),
+            ["after"] => PseudoElement("::after"),
+            ["backdrop"] => PseudoElement("::backdrop"),
+            ["marker"] => PseudoElement("::marker"),
+            ["placeholder"] => PseudoElement("::placeholder"),
+            ["selection"] => PseudoElement("::selection"),
+            ["file"] => PseudoElement("::file-selector-button"),
+            ["first", "line"] => PseudoElement("::first-line"),
+            ["first", "letter"] => PseudoElement("::first-letter"),
+            // https://developer.mozilla.org/en-US/docs/Web/CSS/Pseudo-classes
+            ["hover"] => PseudoClass(":hover"),
+            ["focus"] => PseudoClass(":focus"),
+            ["focus", "within"] => PseudoClass(":focus-within"),
+            ["focus", "visible"] => PseudoClass(":focus-visible"),
+            ["active"] => PseudoClass(":active"),
+            ["visited"] => PseudoClass(":visited"),
+            ["target"] => PseudoClass(":target"),
+            ["only"] => PseudoClass(":only-child"),
+            ["first", "of", "type"] => PseudoClass(":first-of-type"),
+            ["last", "of", "type"] => PseudoClass(":last-of-type"),
+            ["only", "of", "type"] => PseudoClass(":only-of-type"),
+            ["empty"] => PseudoClass(":empty"),
+            ["disabled"] => PseudoClass(":disabled"),
+            ["enabled"] => PseudoClass(":enabled"),
+            ["checked"] => PseudoClass(":checked"),
+            ["indeterminate"] => PseudoClass(":indeterminate"),
+            ["default"] => PseudoClass(":default"),
+            ["required"] => PseudoClass(":required"),
+            ["optional"] => PseudoClass(":optional"),
+            ["valid"] => PseudoClass(":valid"),
+            ["invalid"] => PseudoClass(":invalid"),
+            ["user", "valid"] => PseudoClass(":user-valid"),
+            ["user", "invalid"] => PseudoClass(":user-invalid"),
+            ["in", "range"] => PseudoClass(":in-range"),
+            ["out", "of", "range"] => PseudoClass(":out-of-range"),
+            ["placeholder", "shown"] => PseudoClass(":placeholder-shown"),
+            ["autofill"] => PseudoClass(":autofill"),
+            ["read", "only"] => PseudoClass(":read-only"),
+            ["open"] => PseudoClass(":is([open], :popover-open)"),
+            ["inert"] => PseudoClass(":is([inert], [inert] *)"),
+            ["ltr"] => PseudoClass(":where(:dir(ltr), [dir=\"ltr\"], [dir=\"ltr\"] *)"),
+            ["rtl"] => PseudoClass(":where(:dir(rtl), [dir=\"rtl\"], [dir=\"rtl\"] *)"),
+            // https://developer.mozilla.org/en-US/docs/Web/CSS/@media
+            ["print"] => Media("print"),
+            ["portrait"] => Media("(orientation: portrait)"),
+            ["landscape"] => Media("(orientation: landscape)"),
+            ["contrast", "more"] => Media("(prefers-contrast: more)"),
+            ["contrast", "less"] => Media("(prefers-contrast: less)"),
+            ["forced", "colors"] => Media("(forced-colors: active)"),
+            // https://github.com/tailwindlabs/tailwindcss-typography#element-modifiers
+            ["prose", "headings"] => Prose("h1, h2, h3, h4, h5, h6, th"),
+            ["prose", "lead"] => Prose("[class~=\"lead\"]"),
+            ["prose", element] => Prose(match *element {
+                "h1" => "h1",
+                "h2" => "h2",
+                "h3" => "h3",
+                "h4" => "h4",
+                "h5" => "h5",
+                "h6" => "h6",
+                "p" => "p",
+                "a" => "a",
+                "blockquote" => "blockquote",
+                "figure" => "figure",
+                "figcaption" => "figcaption",
+                "strong" => "strong",
+                "em" => "em",
+                "kbd" => "kbd",
+                "code" => "code",
+                "pre" => "pre",
+                "ol" => "ol",
+                "ul" => "ul",
+                "li" => "li",
+                "table" => "table",
+                "thead" => "thead",
+                "tr" => "tr",
+                "th" => "th",
+                "td" => "td",
+                "img" => "img",
+                "video" => "video",
+                "hr" => "hr",
+                _ => return syntax_error!("Unknown variant: {}", self),
+            }),
+            _ => return syntax_error!("Unknown variant: {}", self),
+        };
+        if self.pseudo && !matches!(kind, PseudoElement(_)) {
+            return syntax_error!("{} is not a pseudo element", names.join("-"));
+        }
+        Ok(kind)
+    }
+}
+
+impl TailwindVariantKind {
+    /// Get the css of this variant, `not` inverts the condition if possible.
+    pub fn get_variant(&self, ctx: &TailwindBuilder, not: bool) -> Result<CssVariant> {
+        let out = match self {
+            Self::Dark => match &ctx.dark_mode {
+                DarkMode::Media => media("(prefers-color-scheme: dark)", not),
+                DarkMode::Class => pseudo_class(":where(.dark, .dark *)", not),
+                DarkMode::Selector(s) => pseudo_class(&format!(":where({}, {} *)", s, s), not),
+            },
+            Self::Sticky => supports("(position: sticky)", not),
+            Self::MotionReduce => media("(prefers-reduced-motion: reduce)", not),
+            Self::MotionSafe => media("(prefers-reduced-motion: no-preference)", not),
+            Self::First => pseudo_class(":first-child", not),
+            Self::Last => pseudo_class(":last-child", not),
+            Self::Even => pseudo_class(":nth-child(2n)", not),
+            Self::Odd => pseudo_class(":nth-child(odd)", not),
+            Self::PseudoClass(s) => pseudo_class(s, not),
+            Self::Media(s) => media(s, not),
+            _ if not => return syntax_error!("Variant {:?} can not be negated", self),
+            Self::Children => CssVariant::Selector("& > *".to_string()),
+            Self::Siblings => CssVariant::Selector("& ~ *".to_string()),
+            Self::Sibling => CssVariant::Selector("& + *".to_string()),
+            Self::Override => CssVariant::Selector("&&".to_string()),
+            Self::Prose(s) => CssVariant::Selector(format!("& :is({})", TypographySystem::get_selector(s))),
+            Self::PseudoElement(s) => CssVariant::Selector(format!("&{}", s)),
+        };
+        Ok(out)
+    }
+}
+
+fn pseudo_class(class: &str, not: bool) -> CssVariant {
+    match not {
+        true => CssVariant::Selector(format!("&:not({})", class)),
+        false => CssVariant::Selector(format!("&{}", class)),
+    }
+}
+
+fn media(query: &str, not: bool) -> CssVariant {
+    match not {
+        true => CssVariant::AtRule(format!("@media not {}", query)),
+        false => CssVariant::AtRule(format!("@media {}", query)),
+    }
+}
+
+fn supports(condition: &str, not: bool) -> CssVariant {
+    match not {
+        true => CssVariant::AtRule(format!("@supports not {}", condition)),
+        false => CssVariant::AtRule(format!("@supports {}", condition)),
+    }
+}
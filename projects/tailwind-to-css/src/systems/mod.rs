@@ -6,9 +6,16 @@ mod effect_system;
 mod font_system;
 mod instruction;
 mod preflight;
+mod scanner;
+mod spacing_system;
+#[cfg(feature = "config")]
+mod theme;
+mod typography_system;
 mod units;
 
 pub use self::{
     breakpoints::*, builder::*, colors::*, css_global::*, effect_system::*, font_system::*, instruction::*, preflight::*,
-    units::*,
+    scanner::*, spacing_system::*, typography_system::*, units::*,
 };
+#[cfg(feature = "config")]
+pub use self::theme::*;
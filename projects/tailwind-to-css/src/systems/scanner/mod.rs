@@ -0,0 +1,139 @@
+use std::collections::BTreeSet;
+
+use crate::{systems::instruction::TailwindInstruction, TailwindBuilder};
+
+/// Bytes which can appear in a class outside of `[...]`
+fn is_class_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b':' | b'/' | b'.' | b'!' | b'%')
+}
+
+/// Split arbitrary text into the tokens which could be classes, in order of appearance
+///
+/// Like the extractor of the tailwind JIT, this knows nothing about the language of the text,
+/// so `rsx!`/`view!` macros, JSX, Vue, Svelte, Markdown and templates all work the same,
+/// at the cost of false positives such as `static` in rust code.
+///
+/// Anything but whitespace and double quotes can appear inside `[...]`, e.g. `content-['a_b']`.
+pub fn extract_candidates(text: &str) -> Vec<&str> {
+    let bytes = text.as_bytes();
+    let mut out = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        if !is_class_byte(bytes[i]) && bytes[i] != b'[' {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let mut depth = 0usize;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'[' => depth += 1,
+                b']' if depth > 0 => depth -= 1,
+                b if depth > 0 && !b.is_ascii_whitespace() && !matches!(b, b'"' | b'`') => {},
+                b if depth == 0 && is_class_byte(b) => {},
+                _ => break,
+            }
+            i += 1;
+        }
+        if depth != 0 {
+            // `["p-4", "m-2"]` is no class, but may contain some
+            i = start + 1;
+            continue;
+        }
+        let candidate = trim_candidate(&text[start..i]);
+        if !candidate.is_empty() {
+            out.push(candidate);
+        }
+    }
+    out
+}
+
+/// Drop the punctuation around a token, and the `class:` of a svelte directive
+fn trim_candidate(token: &str) -> &str {
+    let token = token.trim_matches([':', '/', '.']);
+    token.strip_prefix("class:").unwrap_or(token)
+}
+
+impl TailwindBuilder {
+    /// Add the classes found in arbitrary text, returns how many were found
+    ///
+    /// The candidates of [`extract_candidates`] which do not compile are ignored without a diagnostic,
+    /// most of them are plain words.
+    pub fn scan(&mut self, text: &str) -> usize {
+        let candidates: BTreeSet<&str> = extract_candidates(text).into_iter().collect();
+        let mut found = 0;
+        for candidate in candidates {
+            if self.is_class(candidate) && self.trace(candidate, false).is_ok() {
+                found += 1;
+            }
+        }
+        found
+    }
+    /// Parsed without the cache, so that rejected words do not fill it
+    fn is_class(&self, candidate: &str) -> bool {
+        let styles = match tailwind_ast::parse_tailwind(candidate) {
+            Ok(o) if o.len() == 1 => o,
+            _ => return false,
+        };
+        styles.into_iter().map(TailwindInstruction::from).all(|item| self.check_instruction(&item).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract() {
+        let rsx = r#"view! { <div class="p-4 md:flex">{name}</div> } rsx! { div { class: "bg-red-500/50", "hi" } }"#;
+        let found = extract_candidates(rsx);
+        assert!(found.contains(&"p-4") && found.contains(&"md:flex") && found.contains(&"bg-red-500/50"));
+        let jsx = r#"<a className={clsx('text-sm', active && "w-[calc(100%-2rem)]")} />"#;
+        let found = extract_candidates(jsx);
+        assert!(found.contains(&"text-sm") && found.contains(&"w-[calc(100%-2rem)]"));
+        let vue = r#"<p :class="{ 'hover:underline': on }" class="grid-cols-[1fr,auto]">"#;
+        let found = extract_candidates(vue);
+        assert!(found.contains(&"hover:underline") && found.contains(&"grid-cols-[1fr,auto]"));
+        let svelte = r#"<p class:font-bold={on} class="content-['a_b']">"#;
+        let found = extract_candidates(svelte);
+        assert!(found.contains(&"font-bold") && found.contains(&"content-['a_b']"));
+        let yew = r#"classes!(["m-2", "[&>*]:underline"])"#;
+        let found = extract_candidates(yew);
+        assert!(found.contains(&"m-2") && found.contains(&"[&>*]:underline"));
+        let md = "Use `text-lg/7` for a lead paragraph.";
+        assert!(extract_candidates(md).contains(&"text-lg/7"));
+    }
+
+    #[test]
+    fn test_scan() {
+        let mut builder = TailwindBuilder::default();
+        builder.preflight.disable = true;
+        let source = r#"
+            fn card(cx: Scope) -> impl IntoView {
+                view! { cx, <div class="p-4 hover:bg-sky-500 std::fs">{"{% if x %}m-2{% endif %}"}</div> }
+            }
+        "#;
+        assert_eq!(builder.scan(source), 3);
+        assert!(builder.diagnostics().is_empty());
+        let bundle = builder.bundle().unwrap();
+        assert!(bundle.contains(".p-4") && bundle.contains(".hover\\:bg-sky-500") && bundle.contains(".m-2"));
+        assert!(!bundle.contains("std"));
+    }
+
+    #[test]
+    fn test_scan_plain_text() {
+        let mut builder = TailwindBuilder::default();
+        builder.preflight.disable = true;
+        let text = r#"
+            Set text-decoration and font-family in the stylesheet, then pick a p-value or text-muted
+            for the gap-between the cards: `.card { text-decoration: none; font-family: serif; }`
+            font-weight font-size line-height max-width min-height z-index border-radius border-color background-color
+            list-style text-align text-shadow box-shadow outline-offset grid-template row-gap column-gap flex-direction
+            align-items justify-content place-items object-fit overflow-x white-space word-break letter-spacing
+            text-transform vertical-align pointer-events user-select scroll-behavior transition-duration
+            loop { break } content text-overflow col-start-full
+        "#;
+        assert_eq!(builder.scan(text), 0);
+        assert!(builder.bundle().unwrap().is_empty());
+    }
+}
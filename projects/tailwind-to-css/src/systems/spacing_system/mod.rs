@@ -0,0 +1,80 @@
+use crate::LengthUnit;
+use std::collections::BTreeMap;
+
+/// The spacing scale shared by padding, margin, gap, inset and space
+///
+/// <https://tailwindcss.com/docs/customizing-spacing>
+#[derive(Clone, Debug)]
+pub struct SpacingSystem {
+    /// The size of one numeric step, `p-4` => `4 * base`
+    base: LengthUnit,
+    /// Named steps like `gutter`, and numeric steps that do not follow the base unit
+    steps: BTreeMap<String, String>,
+}
+
+impl Default for SpacingSystem {
+    fn default() -> Self {
+        Self { base: LengthUnit::rem(0.25), steps: Default::default() }
+    }
+}
+
+impl SpacingSystem {
+    /// Builtin spacing scale, every step is `0.25rem`
+    pub fn builtin() -> Self {
+        Self::default()
+    }
+    /// Set the size of one numeric step, e.g. `4px`
+    #[inline]
+    pub fn set_base(&mut self, base: LengthUnit) {
+        self.base = base;
+    }
+    /// Insert a named step, e.g. `gutter` => `1.5rem`
+    #[inline]
+    pub fn insert_step(&mut self, name: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.steps.insert(name.into(), value.into())
+    }
+    /// Remove all named steps, numeric steps fall back to the base unit
+    #[inline]
+    pub fn clear_steps(&mut self) {
+        self.steps.clear()
+    }
+    /// Get the named step, `None` if not registered
+    #[inline]
+    pub fn get_step(&self, name: &str) -> Option<String> {
+        self.steps.get(name).cloned()
+    }
+    /// Get the numeric step, never fail, fallback to `n * base`
+    pub fn get_number(&self, n: f32) -> String {
+        match self.steps.get(&n.abs().to_string()) {
+            Some(s) if n < 0.0 => format!("calc({} * -1)", s),
+            Some(s) => s.to_string(),
+            None => match self.base {
+                LengthUnit::Unit(x, unit) => LengthUnit::Unit(n * x, unit).get_properties(),
+                LengthUnit::Fraction(a, b) => LengthUnit::percent(n * 100.0 * a as f32 / b as f32).get_properties(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LengthUnit, TailwindBuilder};
+
+    #[test]
+    fn test_spacing_base() {
+        let mut tw = TailwindBuilder::default();
+        assert_eq!(tw.inline("p-4").unwrap().1, "padding:1rem;");
+        // unknown steps are rejected instead of being written as is
+        for class in ["p-auto", "p-gutter", "m-gutter", "gap-between", "space-x-gutter", "inset-gutter"] {
+            assert!(tw.inline(class).is_err(), "{}", class);
+        }
+        assert_eq!(tw.inline("inset-auto").unwrap().1, "bottom:auto;left:auto;right:auto;top:auto;");
+        tw.spacing.set_base(LengthUnit::px(4.0));
+        tw.spacing.insert_step("gutter", "24px");
+        assert_eq!(tw.inline("p-4").unwrap().1, "padding:16px;");
+        assert_eq!(tw.inline("gap-x-2").unwrap().1, "column-gap:8px;");
+        assert_eq!(tw.inline("space-y-gutter").unwrap().1, "margin-top:24px;");
+        assert_eq!(tw.inline("inset-x-gutter").unwrap().1, "left:24px;right:24px;");
+        assert_eq!(tw.inline("-inset-1").unwrap().1, "bottom:-4px;left:-4px;right:-4px;top:-4px;");
+    }
+}
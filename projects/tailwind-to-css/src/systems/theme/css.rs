@@ -0,0 +1,244 @@
+use super::*;
+
+impl TailwindBuilder {
+    /// Create a builder from the `@theme { }` blocks of a tailwind v4 stylesheet
+    pub fn from_theme_css(css: &str) -> Result<Self> {
+        let mut builder = Self::default();
+        builder.apply_theme_css(css)?;
+        Ok(builder)
+    }
+    /// Apply the `@theme { }` blocks of a tailwind v4 stylesheet to the registries of this builder
+    ///
+    /// Variables extend the builtin theme, unless their namespace is reset by `--color-*: initial` or `--*: initial`.
+    pub fn apply_theme_css(&mut self, css: &str) -> Result<()> {
+        self.apply_theme(&ThemeConfig::parse_css(css)?)
+    }
+}
+
+impl ThemeConfig {
+    /// Collect the variables of the `@theme { }` blocks in a tailwind v4 stylesheet
+    ///
+    /// - `--color-brand-500: oklch(60.6% 0.25 292.717)` => `colors.brand.500`
+    /// - `--color-primary: #0EA5E9` => `colors.primary`
+    /// - `--color-ink: var(--color-red-500)` => `colors.ink`, resolved against the registered palettes
+    /// - `--breakpoint-3xl: 1920px` => `screens.3xl`
+    /// - `--font-display: Oswald, sans-serif` => `fontFamily.display`
+    /// - `--text-huge: 5rem`, `--text-huge--line-height: 1` => `fontSize.huge`
+    /// - `--tracking-loose: 0.2em` => `letterSpacing.loose`
+    /// - `--shadow-soft`, `--drop-shadow-glow` => `boxShadow.soft`, `dropShadow.glow`
+    /// - `--spacing: 0.25rem`, `--spacing-gutter: 1.5rem` => `spacing.DEFAULT`, `spacing.gutter`
+    ///
+    /// <https://tailwindcss.com/docs/theme#theme-variable-namespaces>
+    pub fn parse_css(css: &str) -> Result<Self> {
+        let mut reset = Self::default();
+        let mut extend = Self::default();
+        let mut sizes: BTreeMap<String, (Option<String>, Option<String>)> = BTreeMap::new();
+        for (name, value) in theme_variables(css)? {
+            if value == "initial" {
+                reset.reset_namespace(&name);
+                continue;
+            }
+            if let Some(rest) = name.strip_prefix("text-") {
+                match rest.split_once("--") {
+                    None => sizes.entry(rest.to_string()).or_default().0 = Some(value),
+                    Some((size, "line-height")) => sizes.entry(size.to_string()).or_default().1 = Some(value),
+                    Some(_) => {},
+                }
+                continue;
+            }
+            // sub-properties such as `--font-display--font-feature-settings` have no registry
+            if name.contains("--") {
+                continue;
+            }
+            extend.insert_variable(&name, value)?;
+        }
+        for (name, size) in sizes {
+            let size = match size {
+                (Some(size), None) => ThemeFontSize::Size(size),
+                (Some(size), Some(height)) => ThemeFontSize::WithLineHeight(size, height),
+                (None, _) => continue,
+            };
+            extend.font_size.get_or_insert_with(Default::default).insert(name, size);
+        }
+        reset.extend = Some(Box::new(extend));
+        Ok(reset)
+    }
+    /// `--color-*: initial` clears the builtin colors, `--*: initial` clears everything
+    fn reset_namespace(&mut self, name: &str) {
+        let all = name == "*";
+        if all || name == "color-*" {
+            self.colors = Some(Default::default());
+        }
+        if all || name == "breakpoint-*" {
+            self.screens = Some(Default::default());
+        }
+        if all || name == "text-*" {
+            self.font_size = Some(Default::default());
+        }
+        if all || name == "font-*" {
+            self.font_family = Some(Default::default());
+        }
+        if all || name == "tracking-*" {
+            self.letter_spacing = Some(Default::default());
+        }
+        if all || name == "shadow-*" {
+            self.box_shadow = Some(Default::default());
+        }
+        if all || name == "drop-shadow-*" {
+            self.drop_shadow = Some(Default::default());
+        }
+        if all || name == "spacing-*" {
+            self.spacing = Some(Default::default());
+        }
+    }
+    fn insert_variable(&mut self, name: &str, value: String) -> Result<()> {
+        // `--font-weight-*` belongs to `font-bold` and friends
+        if name.starts_with("font-weight-") {
+            return Ok(());
+        }
+        if let Some(color) = name.strip_prefix("color-") {
+            insert_color(self.colors.get_or_insert_with(Default::default), color, value);
+        }
+        else if let Some(screen) = name.strip_prefix("breakpoint-") {
+            self.screens.get_or_insert_with(Default::default).insert(screen.to_string(), parse_px(&value)?);
+        }
+        else if let Some(family) = name.strip_prefix("font-") {
+            self.font_family.get_or_insert_with(Default::default).insert(family.to_string(), ThemeList::One(value));
+        }
+        else if let Some(tracking) = name.strip_prefix("tracking-") {
+            self.letter_spacing.get_or_insert_with(Default::default).insert(tracking.to_string(), value);
+        }
+        else if let Some(shadow) = name.strip_prefix("drop-shadow-") {
+            self.drop_shadow.get_or_insert_with(Default::default).insert(shadow.to_string(), ThemeList::One(value));
+        }
+        else if let Some(shadow) = name.strip_prefix("shadow-") {
+            self.box_shadow.get_or_insert_with(Default::default).insert(shadow.to_string(), ThemeList::One(value));
+        }
+        else if name == "spacing" {
+            self.spacing.get_or_insert_with(Default::default).insert("DEFAULT".to_string(), value);
+        }
+        else if let Some(step) = name.strip_prefix("spacing-") {
+            self.spacing.get_or_insert_with(Default::default).insert(step.to_string(), value);
+        }
+        Ok(())
+    }
+}
+
+/// `brand-500` goes into the `brand` palette, `brand` and `brand-light` are keywords
+fn insert_color(colors: &mut BTreeMap<String, ThemeColor>, name: &str, value: String) {
+    let (name, weight) = match name.rsplit_once('-') {
+        Some((name, weight)) if weight.parse::<u32>().is_ok() => (name, weight),
+        _ => (name, "DEFAULT"),
+    };
+    let entry = colors.entry(name.to_string()).or_insert_with(|| ThemeColor::Palette(BTreeMap::new()));
+    if let ThemeColor::Keyword(keyword) = entry {
+        *entry = ThemeColor::Palette(BTreeMap::from([("DEFAULT".to_string(), keyword.to_string())]));
+    }
+    if let ThemeColor::Palette(weights) = entry {
+        weights.insert(weight.to_string(), value);
+    }
+}
+
+/// v4 declares breakpoints in `rem`, the registry orders them in whole pixels
+fn parse_px(width: &str) -> Result<String> {
+    match width.strip_suffix("rem").map(|s| s.trim().parse::<f32>()) {
+        Some(Ok(o)) => Ok(format!("{}px", (o * 16.0).round())),
+        Some(Err(_)) => syntax_error!("Breakpoint must be a length, found: {}", width),
+        None => Ok(width.to_string()),
+    }
+}
+
+/// The `--name: value` declarations of all `@theme` blocks, in order of appearance
+fn theme_variables(css: &str) -> Result<Vec<(String, String)>> {
+    let css = strip_comments(css);
+    let mut out = vec![];
+    let mut rest = css.as_str();
+    while let Some(start) = rest.find("@theme") {
+        // `@theme inline { }` and `@theme static { }` carry the same variables
+        let after = &rest[start + "@theme".len()..];
+        let open = match after.find(['{', ';']) {
+            Some(i) if after[i..].starts_with('{') => i,
+            Some(i) => {
+                rest = &after[i + 1..];
+                continue;
+            },
+            None => return syntax_error!("Expected `{{` after @theme"),
+        };
+        let body = &after[open + 1..];
+        let close = match find_block_end(body) {
+            Some(i) => i,
+            None => return syntax_error!("Unclosed @theme block"),
+        };
+        for statement in split_statements(&body[..close]) {
+            if let Some((name, value)) = statement.strip_prefix("--").and_then(|s| s.split_once(':')) {
+                out.push((name.trim().to_string(), value.trim().to_string()));
+            }
+        }
+        rest = &body[close + 1..];
+    }
+    Ok(out)
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The index of the `}` closing the block, skipping nested blocks and quoted strings
+fn find_block_end(body: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote = None;
+    for (i, c) in body.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {},
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '{') => depth += 1,
+            (None, '}') if depth == 0 => return Some(i),
+            (None, '}') => depth -= 1,
+            _ => {},
+        }
+    }
+    None
+}
+
+/// Split declarations at `;`, nested blocks such as `@keyframes` end their own statement
+fn split_statements(body: &str) -> Vec<&str> {
+    let mut out = vec![];
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in body.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {},
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '{') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, '}') => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    out.push(body[start..=i].trim());
+                    start = i + 1;
+                }
+            },
+            (None, ';') if depth == 0 => {
+                out.push(body[start..i].trim());
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    out.push(body[start..].trim());
+    out.retain(|s| !s.is_empty());
+    out
+}
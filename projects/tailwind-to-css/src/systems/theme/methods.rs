@@ -0,0 +1,179 @@
+use super::*;
+
+impl TailwindBuilder {
+    /// Create a builder from the JSON-serialised `theme` of a `tailwind.config.js`
+    ///
+    /// e.g. `JSON.stringify(require("./tailwind.config.js").theme)`
+    pub fn from_theme_json(json: &str) -> Result<Self> {
+        let theme: ThemeConfig = serde_json::from_str(json)?;
+        let mut builder = Self::default();
+        builder.apply_theme(&theme)?;
+        Ok(builder)
+    }
+    /// Apply the theme to the registries of this builder
+    ///
+    /// Sections given at top level replace the current ones, sections in `extend` are merged into them.
+    pub fn apply_theme(&mut self, theme: &ThemeConfig) -> Result<()> {
+        theme.apply(self, true)?;
+        if let Some(extend) = &theme.extend {
+            extend.apply(self, false)?;
+        }
+        Ok(())
+    }
+}
+
+impl ThemeConfig {
+    fn apply(&self, ctx: &mut TailwindBuilder, replace: bool) -> Result<()> {
+        if let Some(colors) = &self.colors {
+            if replace {
+                ctx.palettes = PaletteSystem::default();
+            }
+            // `--color-ink: var(--color-brand-500)` is resolved once the palettes are registered
+            let (references, colors): (Vec<_>, Vec<_>) = colors.iter().partition(|(_, color)| color.is_reference());
+            for (name, color) in colors.into_iter().chain(references) {
+                color.apply(name, &mut ctx.palettes)?;
+            }
+        }
+        if let Some(screens) = &self.screens {
+            if replace {
+                ctx.screens = BreakPointSystem::default();
+            }
+            for (name, width) in screens {
+                ctx.screens.register(name.to_string(), BreakPointSystem::parse_width(width)?);
+            }
+        }
+        if let Some(sizes) = &self.font_size {
+            if replace {
+                ctx.fonts.clear_size();
+            }
+            for (name, size) in sizes {
+                ctx.fonts.insert_size(name, size.as_font_size()?);
+            }
+        }
+        if let Some(families) = &self.font_family {
+            if replace {
+                ctx.fonts.clear_family();
+            }
+            for (name, family) in families {
+                ctx.fonts.insert_family(name, &family.join(", "));
+            }
+        }
+        if let Some(tracking) = &self.letter_spacing {
+            if replace {
+                ctx.fonts.clear_tracking();
+            }
+            for (name, spacing) in tracking {
+                ctx.fonts.insert_tracking(name, parse_em(spacing)?);
+            }
+        }
+        if let Some(shadows) = &self.box_shadow {
+            if replace {
+                ctx.effects.clear_box_shadows();
+            }
+            for (name, shadow) in shadows {
+                match name.as_str() {
+                    "DEFAULT" => ctx.effects.set_box_shadow_default(shadow.join(", ")),
+                    _ => ctx.effects.insert_box_shadow(name, shadow.join(", ")),
+                }
+            }
+        }
+        if let Some(shadows) = &self.drop_shadow {
+            if replace {
+                ctx.effects.clear_drop_shadows();
+            }
+            for (name, shadow) in shadows {
+                let filter = shadow.as_slice().iter().map(|s| format!("drop-shadow({})", s)).collect::<Vec<_>>().join(" ");
+                match name.as_str() {
+                    "DEFAULT" => ctx.effects.set_drop_shadow_default(filter),
+                    _ => ctx.effects.insert_drop_shadow(name, filter),
+                }
+            }
+        }
+        if let Some(spacing) = &self.spacing {
+            if replace {
+                ctx.spacing.clear_steps();
+            }
+            for (name, value) in spacing {
+                match name.as_str() {
+                    // the unit of numeric steps, `p-4` is `4 * DEFAULT`
+                    "DEFAULT" => ctx.spacing.set_base(LengthUnit::parse_length(value)?),
+                    _ => {
+                        ctx.spacing.insert_step(name, value);
+                    },
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ThemeColor {
+    fn is_reference(&self) -> bool {
+        match self {
+            Self::Keyword(color) => color.starts_with("var("),
+            Self::Palette(weights) => weights.values().any(|color| color.starts_with("var(")),
+        }
+    }
+    fn apply(&self, name: &str, palettes: &mut PaletteSystem) -> Result<()> {
+        let weights = match self {
+            // `transparent` and `current` are builtin keywords
+            Self::Keyword(color) if is_css_keyword(color) => return Ok(()),
+            Self::Keyword(color) => return palettes.register_keyword(name.to_string(), color),
+            Self::Palette(weights) => weights,
+        };
+        let mut colors = BTreeMap::default();
+        for (weight, color) in weights {
+            if let Ok(o) = weight.parse::<u32>() {
+                colors.insert(o, palettes.parse_color(color)?);
+            }
+        }
+        if !colors.is_empty() {
+            palettes.register_merged(name.to_string(), Palette::new(colors));
+        }
+        // the keywords may refer to the weights, `--color-brand: var(--color-brand-600)`
+        for (weight, color) in weights {
+            match weight.as_str() {
+                _ if weight.parse::<u32>().is_ok() => {},
+                // `bg-brand` uses the `DEFAULT` color
+                "DEFAULT" => palettes.register_keyword(name.to_string(), color)?,
+                // `bg-brand-light`
+                _ => palettes.register_keyword(format!("{}-{}", name, weight), color)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ThemeFontSize {
+    fn as_font_size(&self) -> Result<FontSize> {
+        match self {
+            Self::Size(size) => FontSize::parse(size, None),
+            Self::WithLineHeight(size, height) => FontSize::parse(size, Some(height)),
+            Self::WithOptions(size, options) => FontSize::parse(size, options.line_height.as_deref()),
+        }
+    }
+}
+
+impl ThemeList {
+    fn as_slice(&self) -> &[String] {
+        match self {
+            Self::One(s) => std::slice::from_ref(s),
+            Self::Many(v) => v,
+        }
+    }
+    fn join(&self, separator: &str) -> String {
+        self.as_slice().join(separator)
+    }
+}
+
+fn is_css_keyword(color: &str) -> bool {
+    matches!(color, "transparent" | "currentColor" | "inherit" | "initial" | "unset")
+}
+
+/// Letter spacing is stored in `em`
+fn parse_em(input: &str) -> Result<f32> {
+    match input.strip_suffix("em").map(|s| s.parse::<f32>()) {
+        Some(Ok(o)) => Ok(o),
+        _ => syntax_error!("Letter spacing must be in em, found: {}", input),
+    }
+}
@@ -0,0 +1,203 @@
+use crate::*;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+mod css;
+mod methods;
+
+/// The `theme` section of a config file, in the shape of `tailwind.config.js`
+///
+/// Every section given here replaces the builtin one, sections in `extend` are merged into it.
+///
+/// <https://tailwindcss.com/docs/theme>
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// `brand = "#9A66FF"` or `brand = { 50 = "#F5F3FF", 500 = "#8B5CF6" }`
+    pub colors: Option<BTreeMap<String, ThemeColor>>,
+    /// `md = "768px"`
+    pub screens: Option<BTreeMap<String, String>>,
+    /// `sm = "0.875rem"` or `sm = ["0.875rem", "1.25rem"]`
+    #[serde(alias = "fontSize")]
+    pub font_size: Option<BTreeMap<String, ThemeFontSize>>,
+    /// `sans = ["Inter", "sans-serif"]`
+    #[serde(alias = "fontFamily")]
+    pub font_family: Option<BTreeMap<String, ThemeList>>,
+    /// `tight = "-0.025em"`
+    #[serde(alias = "letterSpacing")]
+    pub letter_spacing: Option<BTreeMap<String, String>>,
+    /// `DEFAULT = "0 1px 3px 0 rgb(0 0 0 / 0.1)"`
+    #[serde(alias = "boxShadow")]
+    pub box_shadow: Option<BTreeMap<String, ThemeList>>,
+    /// `md = ["0 4px 3px rgb(0 0 0 / 0.07)", "0 2px 2px rgb(0 0 0 / 0.06)"]`
+    #[serde(alias = "dropShadow")]
+    pub drop_shadow: Option<BTreeMap<String, ThemeList>>,
+    /// `gutter = "1.5rem"`, numeric steps not given here follow the base unit `DEFAULT = "0.25rem"`
+    pub spacing: Option<BTreeMap<String, String>>,
+    /// Sections merged into the builtin ones
+    pub extend: Option<Box<ThemeConfig>>,
+}
+
+/// A single color keyword, or a palette of weighted colors
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ThemeColor {
+    /// `primary = "#FF0000"`
+    Keyword(String),
+    /// `brand = { 50 = "#F5F3FF", light = "#DDD6FE", DEFAULT = "#8B5CF6" }`
+    Palette(BTreeMap<String, String>),
+}
+
+/// A font size with an optional line height
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ThemeFontSize {
+    /// `"0.875rem"`
+    Size(String),
+    /// `["0.875rem", "1.25rem"]`
+    WithLineHeight(String, String),
+    /// `["0.875rem", { lineHeight = "1.25rem" }]`
+    WithOptions(String, ThemeFontSizeOptions),
+}
+
+/// The options of [`ThemeFontSize::WithOptions`]
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ThemeFontSizeOptions {
+    #[serde(alias = "lineHeight")]
+    pub line_height: Option<String>,
+}
+
+/// One value or a list of values, e.g. a font stack or layered shadows
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ThemeList {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THEME: &str = r##"{
+        "screens": { "tablet": "640px", "laptop": "1024px" },
+        "colors": {
+            "transparent": "transparent",
+            "current": "currentColor",
+            "brand": { "light": "#DDD6FE", "500": "#8B5CF6", "DEFAULT": "#7C3AED" }
+        },
+        "fontFamily": { "display": ["Oswald", "sans-serif"] },
+        "spacing": { "gutter": "1.5rem", "4": "18px" },
+        "extend": {
+            "colors": { "brand": { "900": "#4C1D95" } },
+            "fontSize": { "huge": ["5rem", { "lineHeight": "1", "fontWeight": "700" }] },
+            "letterSpacing": { "loose": "0.2em" },
+            "boxShadow": { "soft": ["0 2px 15px -3px #00000012", "0 10px 20px -2px #0000000a"] },
+            "dropShadow": { "glow": "0 0 8px #8B5CF6" }
+        }
+    }"##;
+
+    const THEME_CSS: &str = r#"
+        @import "tailwindcss";
+        /* the brand palette */
+        @theme {
+            --breakpoint-*: initial;
+            --breakpoint-tablet: 40rem;
+            --breakpoint-3xl: 1920px;
+            --color-brand-500: oklch(60.6% 0.25 292.717);
+            --color-brand-600: oklch(0.541 0.281 293.009);
+            --color-brand: var(--color-brand-600);
+            --color-brand-light: oklab(0.894 0.023 -0.06 / 50%);
+            --color-ink: var(--color-red-500);
+            --font-display: "Oswald", sans-serif;
+            --font-weight-extra: 950;
+            --text-huge: 5rem;
+            --text-huge--line-height: 1;
+            --tracking-loose: 0.2em;
+            --shadow-soft: 0 2px 15px -3px #00000012, 0 10px 20px -2px #0000000a;
+            --drop-shadow-glow: 0 0 8px #8B5CF6;
+            --spacing: 4px;
+            --spacing-gutter: 1.5rem;
+            @keyframes wiggle {
+                0%, 100% { transform: rotate(-3deg); }
+                50% { transform: rotate(3deg); }
+            }
+        }
+        .card { padding: 1rem; }
+    "#;
+
+    fn inline(tw: &mut TailwindBuilder, style: &str) -> String {
+        tw.inline(style).unwrap().1
+    }
+
+    #[test]
+    fn test_from_theme_json() {
+        let mut tw = TailwindBuilder::from_theme_json(THEME).unwrap();
+        assert_eq!(inline(&mut tw, "bg-brand-500"), "background-color:rgba(139, 92, 246, 1);");
+        assert_eq!(inline(&mut tw, "bg-brand-900"), "background-color:rgba(76, 29, 149, 1);");
+        assert_eq!(inline(&mut tw, "bg-brand"), "background-color:rgba(124, 58, 237, 1);");
+        assert_eq!(inline(&mut tw, "bg-brand-light"), "background-color:rgba(221, 214, 254, 1);");
+        assert_eq!(inline(&mut tw, "font-display"), "font-family:Oswald, sans-serif;");
+        assert_eq!(inline(&mut tw, "text-huge"), "font-size:5rem;line-height:1;");
+        assert_eq!(inline(&mut tw, "tracking-loose"), "letter-spacing:0.2em;");
+        assert_eq!(inline(&mut tw, "shadow-soft"), "box-shadow:0 2px 15px -3px #00000012, 0 10px 20px -2px #0000000a;");
+        assert_eq!(inline(&mut tw, "drop-shadow-glow"), "filter:drop-shadow(0 0 8px #8B5CF6);");
+        assert_eq!(inline(&mut tw, "p-gutter"), "padding:1.5rem;");
+        assert_eq!(inline(&mut tw, "gap-4"), "gap:18px;");
+        assert_eq!(inline(&mut tw, "m-2"), "margin:0.5rem;");
+        // replaced sections drop the builtin values
+        assert!(tw.screens.try_get_width("md").is_err());
+        assert_eq!(tw.screens.try_get_width("laptop"), Ok(1024));
+        assert!(tw.palettes.try_get_color("red", 500).is_err());
+    }
+
+    #[test]
+    fn test_from_theme_css() {
+        let mut tw = TailwindBuilder::from_theme_css(THEME_CSS).unwrap();
+        assert_eq!(inline(&mut tw, "bg-brand-500"), "background-color:rgba(142, 81, 255, 1);");
+        assert_eq!(inline(&mut tw, "bg-brand"), "background-color:rgba(127, 34, 254, 1);");
+        assert_eq!(inline(&mut tw, "bg-brand-light"), "background-color:rgba(219, 213, 255, 0.5);");
+        assert_eq!(inline(&mut tw, "bg-ink"), "background-color:rgba(251, 44, 54, 1);");
+        assert_eq!(inline(&mut tw, "bg-red-500"), "background-color:rgba(251, 44, 54, 1);");
+        assert_eq!(inline(&mut tw, "font-display"), "font-family:\"Oswald\", sans-serif;");
+        assert_eq!(inline(&mut tw, "text-huge"), "font-size:5rem;line-height:1;");
+        assert_eq!(inline(&mut tw, "tracking-loose"), "letter-spacing:0.2em;");
+        assert_eq!(inline(&mut tw, "shadow-soft"), "box-shadow:0 2px 15px -3px #00000012, 0 10px 20px -2px #0000000a;");
+        assert_eq!(inline(&mut tw, "drop-shadow-glow"), "filter:drop-shadow(0 0 8px #8B5CF6);");
+        assert_eq!(inline(&mut tw, "p-gutter"), "padding:1.5rem;");
+        assert_eq!(inline(&mut tw, "m-2"), "margin:8px;");
+        // `--breakpoint-*: initial` drops the builtin screens
+        assert!(tw.screens.try_get_width("md").is_err());
+        assert_eq!(tw.screens.try_get_width("tablet"), Ok(640));
+        assert_eq!(tw.screens.try_get_width("3xl"), Ok(1920));
+    }
+
+    #[test]
+    fn test_unknown_names() {
+        let mut tw = TailwindBuilder::default();
+        for class in ["shadow", "shadow-md", "drop-shadow", "drop-shadow-lg", "tracking-wide", "tracking-normal"] {
+            assert!(tw.inline(class).is_ok(), "{}", class);
+        }
+        // only the names of the theme are known, instead of falling back to the default value
+        for class in ["shadow-soft", "drop-shadow-glow", "tracking-loose"] {
+            assert!(tw.inline(class).is_err(), "{}", class);
+        }
+    }
+
+    #[test]
+    fn test_from_theme_css_invalid() {
+        assert!(TailwindBuilder::from_theme_css("@theme { --color-brand: #8B5CF6;").is_err());
+        assert!(TailwindBuilder::from_theme_css("@theme { --breakpoint-3xl: wide; }").is_err());
+        assert!(TailwindBuilder::from_theme_css("@theme { --color-brand: nope; }").is_err());
+        assert!(TailwindBuilder::from_theme_css("@theme { --color-brand: oklch(0.5 0.2); }").is_err());
+        assert!(TailwindBuilder::from_theme_css("@theme { --color-brand: var(--color-nope-500); }").is_err());
+    }
+
+    #[test]
+    fn test_from_theme_json_invalid() {
+        assert!(TailwindBuilder::from_theme_json("{ \"screens\": { \"md\": \"48rem\" } }").is_err());
+        assert!(TailwindBuilder::from_theme_json("{ \"colors\": { \"brand\": \"nope\" } }").is_err());
+        assert!(TailwindBuilder::from_theme_json("{ \"colors\": 1 }").is_err());
+    }
+}
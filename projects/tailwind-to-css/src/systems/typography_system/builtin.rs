@@ -0,0 +1,207 @@
+use super::*;
+use crate::css_attributes;
+
+impl TypographySystem {
+    pub fn builtin() -> Self {
+        let mut new = Self::default();
+        new.builtin_default();
+        new.builtin_sizes();
+        new
+    }
+    fn builtin_default(&mut self) {
+        let rules = [
+            ("", css_attributes! {
+                "color" => "var(--tw-prose-body)",
+                "max-width" => "65ch",
+                "font-size" => "1rem",
+                "line-height" => "1.75",
+            }),
+            ("p", css_attributes! {
+                "margin-top" => "1.25em",
+                "margin-bottom" => "1.25em",
+            }),
+            ("[class~=\"lead\"]", css_attributes! {
+                "color" => "var(--tw-prose-lead)",
+                "font-size" => "1.25em",
+                "line-height" => "1.6",
+                "margin-top" => "1.2em",
+                "margin-bottom" => "1.2em",
+            }),
+            ("a", css_attributes! {
+                "color" => "var(--tw-prose-links)",
+                "text-decoration" => "underline",
+                "font-weight" => "500",
+            }),
+            ("strong", css_attributes! {
+                "color" => "var(--tw-prose-bold)",
+                "font-weight" => "600",
+            }),
+            ("ol", css_attributes! {
+                "list-style-type" => "decimal",
+                "margin-top" => "1.25em",
+                "margin-bottom" => "1.25em",
+                "padding-inline-start" => "1.625em",
+            }),
+            ("ul", css_attributes! {
+                "list-style-type" => "disc",
+                "margin-top" => "1.25em",
+                "margin-bottom" => "1.25em",
+                "padding-inline-start" => "1.625em",
+            }),
+            ("li", css_attributes! {
+                "margin-top" => "0.5em",
+                "margin-bottom" => "0.5em",
+            }),
+            ("ol > li::marker", css_attributes! {
+                "font-weight" => "400",
+                "color" => "var(--tw-prose-counters)",
+            }),
+            ("ul > li::marker", css_attributes! {
+                "color" => "var(--tw-prose-bullets)",
+            }),
+            ("hr", css_attributes! {
+                "border-color" => "var(--tw-prose-hr)",
+                "border-top-width" => "1px",
+                "margin-top" => "3em",
+                "margin-bottom" => "3em",
+            }),
+            ("blockquote", css_attributes! {
+                "font-weight" => "500",
+                "font-style" => "italic",
+                "color" => "var(--tw-prose-quotes)",
+                "border-inline-start-width" => "0.25rem",
+                "border-inline-start-color" => "var(--tw-prose-quote-borders)",
+                "quotes" => "\"\\201C\"\"\\201D\"\"\\2018\"\"\\2019\"",
+                "margin-top" => "1.6em",
+                "margin-bottom" => "1.6em",
+                "padding-inline-start" => "1em",
+            }),
+            ("h1", css_attributes! {
+                "color" => "var(--tw-prose-headings)",
+                "font-weight" => "800",
+                "font-size" => "2.25em",
+                "margin-top" => "0",
+                "margin-bottom" => "0.8888889em",
+                "line-height" => "1.1111111",
+            }),
+            ("h2", css_attributes! {
+                "color" => "var(--tw-prose-headings)",
+                "font-weight" => "700",
+                "font-size" => "1.5em",
+                "margin-top" => "2em",
+                "margin-bottom" => "1em",
+                "line-height" => "1.3333333",
+            }),
+            ("h3", css_attributes! {
+                "color" => "var(--tw-prose-headings)",
+                "font-weight" => "600",
+                "font-size" => "1.25em",
+                "margin-top" => "1.6em",
+                "margin-bottom" => "0.6em",
+                "line-height" => "1.6",
+            }),
+            ("h4", css_attributes! {
+                "color" => "var(--tw-prose-headings)",
+                "font-weight" => "600",
+                "margin-top" => "1.5em",
+                "margin-bottom" => "0.5em",
+                "line-height" => "1.5",
+            }),
+            ("img", css_attributes! {
+                "margin-top" => "2em",
+                "margin-bottom" => "2em",
+            }),
+            ("figcaption", css_attributes! {
+                "color" => "var(--tw-prose-captions)",
+                "font-size" => "0.875em",
+                "line-height" => "1.4285714",
+                "margin-top" => "0.8571429em",
+            }),
+            ("code", css_attributes! {
+                "color" => "var(--tw-prose-code)",
+                "font-weight" => "600",
+                "font-size" => "0.875em",
+            }),
+            ("code::before", css_attributes! {
+                "content" => "\"`\"",
+            }),
+            ("code::after", css_attributes! {
+                "content" => "\"`\"",
+            }),
+            ("pre", css_attributes! {
+                "color" => "var(--tw-prose-pre-code)",
+                "background-color" => "var(--tw-prose-pre-bg)",
+                "overflow-x" => "auto",
+                "font-weight" => "400",
+                "font-size" => "0.875em",
+                "line-height" => "1.7142857",
+                "margin-top" => "1.7142857em",
+                "margin-bottom" => "1.7142857em",
+                "border-radius" => "0.375rem",
+                "padding" => "0.8571429em 1.1428571em",
+            }),
+            ("table", css_attributes! {
+                "width" => "100%",
+                "table-layout" => "auto",
+                "margin-top" => "2em",
+                "margin-bottom" => "2em",
+                "font-size" => "0.875em",
+                "line-height" => "1.7142857",
+            }),
+            ("thead", css_attributes! {
+                "border-bottom-width" => "1px",
+                "border-bottom-color" => "var(--tw-prose-th-borders)",
+            }),
+            ("thead th", css_attributes! {
+                "color" => "var(--tw-prose-headings)",
+                "font-weight" => "600",
+                "vertical-align" => "bottom",
+                "padding" => "0 0.5714286em 0.5714286em",
+            }),
+            ("tbody tr", css_attributes! {
+                "border-bottom-width" => "1px",
+                "border-bottom-color" => "var(--tw-prose-td-borders)",
+            }),
+            ("tbody td", css_attributes! {
+                "vertical-align" => "baseline",
+                "padding" => "0.5714286em",
+            }),
+        ];
+        for (element, attributes) in rules {
+            self.insert_rule("DEFAULT", element, attributes);
+        }
+    }
+    fn builtin_sizes(&mut self) {
+        // font-size, line-height, paragraph margin, h1, h2, h3 as (font-size, margin-top, margin-bottom, line-height), code
+        let sizes = [
+            ("sm", "0.875rem", "1.7142857", "1.1428571em", ("2.1428571em", "0", "0.8em", "1.2"), ("1.4285714em", "1.6em", "0.8em", "1.4"), ("1.2857143em", "1.5555556em", "0.4444444em", "1.5555556"), "0.8571429em"),
+            ("base", "1rem", "1.75", "1.25em", ("2.25em", "0", "0.8888889em", "1.1111111"), ("1.5em", "2em", "1em", "1.3333333"), ("1.25em", "1.6em", "0.6em", "1.6"), "0.875em"),
+            ("lg", "1.125rem", "1.7777778", "1.3333333em", ("2.6666667em", "0", "0.8333333em", "1"), ("1.6666667em", "1.8666667em", "1.0666667em", "1.3333333"), ("1.3333333em", "1.6666667em", "0.6666667em", "1.5"), "0.8888889em"),
+            ("xl", "1.25rem", "1.8", "1.2em", ("2.8em", "0", "0.8571429em", "1"), ("1.8em", "1.5555556em", "0.8888889em", "1.1111111"), ("1.5em", "1.6em", "0.6666667em", "1.3333333"), "0.9em"),
+            ("2xl", "1.5rem", "1.6666667", "1.3333333em", ("2.6666667em", "0", "0.875em", "1"), ("2em", "1.5em", "0.8333333em", "1.0833333"), ("1.5em", "1.5555556em", "0.6666667em", "1.2222222"), "0.8333333em"),
+        ];
+        for (name, font_size, line_height, paragraph, h1, h2, h3, code) in sizes {
+            self.insert_rule(name, "", css_attributes! {
+                "font-size" => font_size,
+                "line-height" => line_height,
+            });
+            self.insert_rule(name, "p", css_attributes! {
+                "margin-top" => paragraph,
+                "margin-bottom" => paragraph,
+            });
+            for (element, (font_size, margin_top, margin_bottom, line_height)) in [("h1", h1), ("h2", h2), ("h3", h3)] {
+                self.insert_rule(name, element, css_attributes! {
+                    "font-size" => font_size,
+                    "margin-top" => margin_top,
+                    "margin-bottom" => margin_bottom,
+                    "line-height" => line_height,
+                });
+            }
+            for element in ["code", "pre"] {
+                self.insert_rule(name, element, css_attributes! {
+                    "font-size" => code,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::CssAttributes;
+use std::collections::BTreeMap;
+
+mod builtin;
+
+/// The element rules of the `prose` classes
+///
+/// `DEFAULT` holds the rules of `prose`, the other styles are the size modifiers such as `prose-lg`.
+/// In every style, the element `""` holds the properties of the prose element itself.
+///
+/// <https://github.com/tailwindlabs/tailwindcss-typography>
+#[derive(Debug, Clone, Default)]
+pub struct TypographySystem {
+    styles: BTreeMap<String, BTreeMap<String, CssAttributes>>,
+}
+
+impl TypographySystem {
+    /// Get the element rules of a style, `None` if not registered
+    #[inline]
+    pub fn get_style(&self, name: &str) -> Option<&BTreeMap<String, CssAttributes>> {
+        self.styles.get(name)
+    }
+    /// Merge the properties into the rule of an element, e.g. `DEFAULT`, `a` => `color: var(--tw-prose-links)`
+    pub fn insert_rule<K, V>(&mut self, style: K, element: V, attributes: CssAttributes)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let rules = self.styles.entry(style.into()).or_default();
+        *rules.entry(element.into()).or_default() += attributes;
+    }
+    /// Remove a style and all of its rules
+    #[inline]
+    pub fn remove_style(&mut self, name: &str) -> Option<BTreeMap<String, CssAttributes>> {
+        self.styles.remove(name)
+    }
+    /// The selector of an element inside a prose element, opted out by `not-prose`
+    ///
+    /// - `a` => `:where(a):not(:where([class~="not-prose"],[class~="not-prose"] *))`
+    /// - `ul > li::marker` => `:where(ul > li):not(...)::marker`
+    pub fn get_selector(element: &str) -> String {
+        let (element, pseudo) = match element.split_once("::") {
+            Some((element, pseudo)) => (element, format!("::{}", pseudo)),
+            None => (element, String::new()),
+        };
+        format!(":where({}):not(:where([class~=\"not-prose\"],[class~=\"not-prose\"] *)){}", element, pseudo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{css_attributes, CssAttributes, TailwindBuilder};
+
+    #[test]
+    fn test_prose_rules() {
+        let mut tw = TailwindBuilder::default();
+        tw.preflight.disable = true;
+        tw.typography.remove_style("DEFAULT");
+        tw.typography.insert_rule("DEFAULT", "", css_attributes! { "max-width" => "none" });
+        tw.typography.insert_rule("DEFAULT", "a", css_attributes! { "color" => "inherit" });
+        tw.typography.insert_rule("tight", "p", css_attributes! { "margin" => "0" });
+        tw.trace("prose-tight", false).unwrap();
+        tw.trace("prose-a:underline", false).unwrap();
+        let css = tw.bundle().unwrap();
+        assert!(css.contains(".prose-tight{& :where(p):not(:where([class~=\"not-prose\"],[class~=\"not-prose\"] *)) { margin:0; }}"));
+        assert!(css.contains(".prose-a\\:underline :is(:where(a):not("));
+        tw.trace("prose", false).unwrap();
+        let css = tw.bundle().unwrap();
+        assert!(css.contains("max-width:none;& :where(a):not(:where([class~=\"not-prose\"],[class~=\"not-prose\"] *)) { color:inherit; }}"));
+        // a name is either a registered style or a palette
+        assert!(tw.trace("prose-slate", false).is_ok());
+        assert!(tw.trace("prose-foo", false).is_err());
+    }
+}
@@ -24,7 +24,7 @@ impl StandardValue {
     }
     pub fn parse_keyword(pattern: &[&str], id: &str, checker: &'static impl Fn(&str) -> bool) -> Result<Self> {
         let keyword = pattern.join("-");
-        if cfg!(feature = "compile_time") && !checker(&keyword) {
+        if !checker(&keyword) {
             return syntax_error!("{} does not a valid value of {}", keyword, id);
         }
         Ok(Self::Keyword(keyword))
@@ -4,7 +4,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use crate::{CssAttributes, TailwindBuilder};
+use crate::{CssAttributes, Result, TailwindBuilder};
 
 pub mod instance;
 
@@ -20,6 +20,11 @@ pub trait TailwindInstance: Display {
     fn inlineable(&self) -> bool {
         true
     }
+    /// Can this instance be renamed in obfuscate mode?
+    /// - (not if other selectors refer to it by name, e.g. `group`)
+    fn obfuscatable(&self) -> bool {
+        true
+    }
     /// New tailwind instance
     fn boxed(self) -> Box<dyn TailwindInstance>
     where
@@ -32,6 +37,10 @@ pub trait TailwindInstance: Display {
     fn selectors(&self, ctx: &TailwindBuilder) -> String {
         format!(".{}", self.id())
     }
+    /// Check the names which can only be resolved against the builder, e.g. the spacing step of `p-gutter`
+    fn validate(&self, ctx: &TailwindBuilder) -> Result<()> {
+        Ok(())
+    }
     /// Attributes in css, representing contained CSS property-value(s)
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes;
     /// Additional css in bundle